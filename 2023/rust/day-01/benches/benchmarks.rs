@@ -1,4 +1,5 @@
-use day_01::*;
+use y2023_day_01::recognizer::{AhoCorasick, Nom};
+use y2023_day_01::*;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,17 +7,35 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = aoc_input::Source::from_env()
+        .read(env!("CARGO_MANIFEST_DIR"), 1)
+        .unwrap();
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
-}
\ No newline at end of file
+fn part2(bencher: divan::Bencher) {
+    let input = aoc_input::Source::from_env()
+        .read(env!("CARGO_MANIFEST_DIR"), 2)
+        .unwrap();
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part2_aho_corasick(bencher: divan::Bencher) {
+    let input = aoc_input::Source::from_env()
+        .read(env!("CARGO_MANIFEST_DIR"), 2)
+        .unwrap();
+    let recognizer = AhoCorasick::default();
+    bencher.bench(|| part2::process_with(&recognizer, divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part2_nom(bencher: divan::Bencher) {
+    let input = aoc_input::Source::from_env()
+        .read(env!("CARGO_MANIFEST_DIR"), 2)
+        .unwrap();
+    let recognizer = Nom::default();
+    bencher.bench(|| part2::process_with(&recognizer, divan::black_box(&input)).unwrap());
+}
@@ -1,5 +1,8 @@
-use day_01::part1::process;
 use miette::Context;
+#[cfg(feature = "tracy")]
+use tracing_subscriber::layer::SubscriberExt;
+use y2023_day_01::custom_error::AocError;
+use y2023_day_01::part1::process;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
@@ -10,11 +13,20 @@ fn main() -> miette::Result<()> {
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
 
-    #[cfg(not(feature = "dhat-heap"))]
+    #[cfg(feature = "tracy")]
+    tracing::subscriber::set_global_default(
+        tracing_subscriber::registry().with(tracing_tracy::TracyLayer::new()),
+    )
+    .expect("no other subscriber is set");
+
+    #[cfg(not(any(feature = "dhat-heap", feature = "tracy")))]
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input1.txt");
-    let result = process(file).context("process part 1")?;
+    let file = aoc_input::Source::from_args()
+        .read(env!("CARGO_MANIFEST_DIR"), 1)
+        .map_err(AocError::from)
+        .context("read input")?;
+    let result = process(&file).context("process part 1")?;
     println!("{}", result);
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,5 +1,10 @@
-use day_01::part2::process;
 use miette::Context;
+#[cfg(feature = "tracy")]
+use tracing_subscriber::layer::SubscriberExt;
+use y2023_day_01::custom_error::AocError;
+use y2023_day_01::part2::process_with;
+use y2023_day_01::recognizer::Scan;
+use y2023_day_01::vocabulary::Vocabulary;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
@@ -10,11 +15,21 @@ fn main() -> miette::Result<()> {
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
 
-    #[cfg(not(feature = "dhat-heap"))]
+    #[cfg(feature = "tracy")]
+    tracing::subscriber::set_global_default(
+        tracing_subscriber::registry().with(tracing_tracy::TracyLayer::new()),
+    )
+    .expect("no other subscriber is set");
+
+    #[cfg(not(any(feature = "dhat-heap", feature = "tracy")))]
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 2")?;
+    let file = aoc_input::Source::from_args()
+        .read(env!("CARGO_MANIFEST_DIR"), 2)
+        .map_err(AocError::from)
+        .context("read input")?;
+    let vocabulary = Vocabulary::from_env().context("read vocabulary")?;
+    let result = process_with(&Scan::new(vocabulary), &file).context("process part 2")?;
     println!("{}", result);
     Ok(())
 }
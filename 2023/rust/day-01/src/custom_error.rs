@@ -1,5 +1,4 @@
-
-use miette::Diagnostic;
+use miette::{Diagnostic, SourceSpan};
 use thiserror::Error;
 
 #[derive(Error, Diagnostic, Debug)]
@@ -7,4 +6,38 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
-}
\ No newline at end of file
+
+    #[error("failed to parse input")]
+    #[diagnostic(code(aoc::parse_error))]
+    ParseError {
+        #[source_code]
+        input: String,
+        #[label("{message}")]
+        span: SourceSpan,
+        message: String,
+    },
+
+    #[error("line {line} has no digit")]
+    #[diagnostic(
+        code(aoc::no_digit),
+        help("a calibration line needs a numeral or a word of the vocabulary")
+    )]
+    NoDigit {
+        #[source_code]
+        input: String,
+        #[label("no digit in this line")]
+        span: SourceSpan,
+        line: usize,
+    },
+}
+
+impl AocError {
+    pub fn parse_error(input: &str, error: impl aoc_parse::Locate) -> Self {
+        let failure = error.locate(input);
+        AocError::ParseError {
+            input: input.to_string(),
+            span: failure.span,
+            message: failure.message,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::custom_error::AocError;
 
-#[tracing::instrument]
+#[tracing::instrument(skip_all)]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
     let lines = input.lines();
     let result = lines
@@ -21,15 +21,19 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
     use super::*;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
-        assert_eq!("142", process(input)?);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part1.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process(&fixture.input)?);
         Ok(())
     }
 }
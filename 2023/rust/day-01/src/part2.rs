@@ -1,63 +1,72 @@
 use crate::custom_error::AocError;
+use crate::recognizer::{Recognizer, Scan};
 
-#[tracing::instrument]
+#[tracing::instrument(skip_all)]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let lines = input.lines();
-    let result = lines
-        .map(|line| {
-            let mut filtered_line = String::new();
-            for index in 0..line.len() {
-                let chunk = &line[index..];
-                let first_char = chunk.chars().nth(0).expect("Chunk should not be empty");
-                if first_char.is_ascii_digit() {
-                    filtered_line.push(first_char)
-                } else {
-                    match chunk {
-                        c if c.starts_with("one") => filtered_line.push('1'),
-                        c if c.starts_with("two") => filtered_line.push('2'),
-                        c if c.starts_with("three") => filtered_line.push('3'),
-                        c if c.starts_with("four") => filtered_line.push('4'),
-                        c if c.starts_with("five") => filtered_line.push('5'),
-                        c if c.starts_with("six") => filtered_line.push('6'),
-                        c if c.starts_with("seven") => filtered_line.push('7'),
-                        c if c.starts_with("eight") => filtered_line.push('8'),
-                        c if c.starts_with("nine") => filtered_line.push('9'),
-                        _ => (),
-                    }
-                }
-            }
-            filtered_line
-        })
-        .map(|line| {
-            let mut chars = line.chars();
-            let first_digit = chars.nth(0).expect("Should be a number");
-            let last_digit = match chars.nth_back(0) {
-                Some(num) => num,
-                None => first_digit,
-            };
-            format!("{}{}", first_digit, last_digit)
-                .parse::<u32>()
-                .expect("Should be valid numbers")
+    process_with(&Scan::default(), input)
+}
+
+/// Part 2 with the digits found by `recognizer`, to compare them.
+pub fn process_with<R: Recognizer + ?Sized>(
+    recognizer: &R,
+    input: &str,
+) -> miette::Result<String, AocError> {
+    let result = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            // `lines` borrows from `input`, so the pointers give the offset.
+            let offset = line.as_ptr() as usize - input.as_ptr() as usize;
+            let (first, last) =
+                recognizer
+                    .first_and_last(line)
+                    .ok_or_else(|| AocError::NoDigit {
+                        input: input.to_string(),
+                        span: (offset, line.len()).into(),
+                        line: index + 1,
+                    })?;
+            Ok(first * 10 + last)
         })
-        .sum::<u32>();
+        .sum::<Result<u32, AocError>>()?;
     Ok(result.to_string())
 }
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
     use super::*;
+    use crate::recognizer::{AhoCorasick, Nom};
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen
-twone";
-        assert_eq!("302", process(input)?);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part2.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process(&fixture.input)?);
         Ok(())
     }
+
+    #[rstest]
+    fn test_process_with(
+        #[values(&Scan::default(), &AhoCorasick::default(), &Nom::default())]
+        recognizer: &dyn Recognizer,
+        #[files("fixtures/*/part2.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process_with(recognizer, &fixture.input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_digit() {
+        let error = process("two1nine\nabcdef\n7pqrstsixteen").unwrap_err();
+        assert!(matches!(
+            error,
+            AocError::NoDigit { span, line: 2, .. } if span == (9, 6).into()
+        ));
+    }
 }
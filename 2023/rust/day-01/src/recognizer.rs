@@ -0,0 +1,191 @@
+use std::cmp::Reverse;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::anychar,
+    combinator::map_opt,
+    error::{Error, ErrorKind},
+    IResult, Parser,
+};
+
+use crate::vocabulary::Vocabulary;
+
+/// A way of finding the digits of a calibration line: numerals, and the
+/// words of a [`Vocabulary`], which may overlap.
+pub trait Recognizer {
+    /// The first and last digit of `line`, `None` when it has none.
+    fn first_and_last(&self, line: &str) -> Option<(u32, u32)>;
+}
+
+/// Tries the numerals and every word at every position of the line.
+#[derive(Debug, Default, Clone)]
+pub struct Scan {
+    vocabulary: Vocabulary,
+}
+
+impl Scan {
+    pub fn new(vocabulary: Vocabulary) -> Self {
+        Self { vocabulary }
+    }
+}
+
+impl Recognizer for Scan {
+    fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        ends(
+            line.char_indices()
+                .filter_map(|(index, c)| match c.to_digit(10) {
+                    Some(digit) => Some(numeral(digit)),
+                    None => self.vocabulary.spelled_at(&line[index..]),
+                }),
+        )
+    }
+}
+
+/// Finds the numerals and words in a single pass with one automaton, built
+/// once up front.
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    automaton: aho_corasick::AhoCorasick,
+    /// The digits of each pattern, by pattern id.
+    digits: Vec<Vec<u32>>,
+}
+
+impl AhoCorasick {
+    pub fn new(vocabulary: Vocabulary) -> Self {
+        let numerals = (0..10).map(|digit: u32| (digit.to_string(), vec![digit]));
+        let words = vocabulary
+            .words()
+            .map(|(word, digits)| (word.to_string(), digits.to_vec()));
+        let (patterns, digits): (Vec<_>, Vec<_>) = numerals.chain(words).unzip();
+        Self {
+            automaton: aho_corasick::AhoCorasick::new(patterns).expect("patterns should be valid"),
+            digits,
+        }
+    }
+}
+
+impl Default for AhoCorasick {
+    fn default() -> Self {
+        Self::new(Vocabulary::english())
+    }
+}
+
+impl Recognizer for AhoCorasick {
+    fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        // Overlapping matches come out by where they end, not where they
+        // start, so `twone` needs sorting out, the longest word winning
+        // among those starting at the same place.
+        let matches = || self.automaton.find_overlapping_iter(line);
+        let first = matches().min_by_key(|found| (found.start(), Reverse(found.len())))?;
+        let last = matches().max_by_key(|found| (found.start(), found.len()))?;
+        let digits = |found: aho_corasick::Match| self.digits[found.pattern()].as_slice();
+        Some((*digits(first).first()?, *digits(last).last()?))
+    }
+}
+
+/// Runs a nom parser for one numeral or word at every position of the line.
+#[derive(Debug, Default, Clone)]
+pub struct Nom {
+    vocabulary: Vocabulary,
+}
+
+impl Nom {
+    pub fn new(vocabulary: Vocabulary) -> Self {
+        Self { vocabulary }
+    }
+
+    fn digits<'a, 's>(&'s self, input: &'a str) -> IResult<&'a str, &'s [u32]> {
+        map_opt(anychar, |c| c.to_digit(10))
+            .map(|digit| -> &'s [u32] { numeral(digit) })
+            .or(|input| self.spelled(input))
+            .parse(input)
+    }
+
+    fn spelled<'a>(&self, input: &'a str) -> IResult<&'a str, &[u32]> {
+        self.vocabulary
+            .words()
+            .find_map(|(word, digits)| {
+                let (rest, _) = tag::<_, _, Error<_>>(word)(input).ok()?;
+                Some((rest, digits))
+            })
+            .ok_or(nom::Err::Error(Error::new(input, ErrorKind::Tag)))
+    }
+}
+
+impl Recognizer for Nom {
+    fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        ends(
+            line.char_indices()
+                .filter_map(|(index, _)| self.digits(&line[index..]).ok())
+                .map(|(_, digits)| digits),
+        )
+    }
+}
+
+fn numeral(digit: u32) -> &'static [u32] {
+    const NUMERALS: [[u32; 1]; 10] = [[0], [1], [2], [3], [4], [5], [6], [7], [8], [9]];
+    &NUMERALS[digit as usize]
+}
+
+/// The first digit of the first hit and the last digit of the last one.
+fn ends<'a>(mut hits: impl Iterator<Item = &'a [u32]>) -> Option<(u32, u32)> {
+    let first = hits.next()?;
+    let last = hits.last().unwrap_or(first);
+    Some((*first.first()?, *last.last()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    type Build = fn(Vocabulary) -> Box<dyn Recognizer>;
+
+    fn scan(vocabulary: Vocabulary) -> Box<dyn Recognizer> {
+        Box::new(Scan::new(vocabulary))
+    }
+
+    fn aho_corasick(vocabulary: Vocabulary) -> Box<dyn Recognizer> {
+        Box::new(AhoCorasick::new(vocabulary))
+    }
+
+    fn nom(vocabulary: Vocabulary) -> Box<dyn Recognizer> {
+        Box::new(Nom::new(vocabulary))
+    }
+
+    #[rstest]
+    #[case("1abc2", Some((1, 2)))]
+    #[case("treb7uchet", Some((7, 7)))]
+    #[case("two1nine", Some((2, 9)))]
+    #[case("zoneight234", Some((1, 4)))]
+    #[case("twone", Some((2, 1)))]
+    #[case("eighthree", Some((8, 3)))]
+    #[case("seveninen", Some((7, 9)))]
+    #[case("abcdef", None)]
+    fn test_first_and_last(
+        #[values(scan, aho_corasick, nom)] build: Build,
+        #[case] line: &str,
+        #[case] expected: Option<(u32, u32)>,
+    ) {
+        assert_eq!(expected, build(Vocabulary::english()).first_and_last(line));
+    }
+
+    #[rstest]
+    #[case("zerone", Some((0, 1)))]
+    #[case("seventeen", Some((1, 7)))]
+    #[case("seventeeneightwelve", Some((1, 2)))]
+    #[case("twelveseven", Some((1, 7)))]
+    #[case("sevenzer", Some((7, 7)))]
+    #[case("eins", None)]
+    fn test_vocabulary(
+        #[values(scan, aho_corasick, nom)] build: Build,
+        #[case] line: &str,
+        #[case] expected: Option<(u32, u32)>,
+    ) -> miette::Result<()> {
+        let vocabulary =
+            Vocabulary::parse("zero 0\none 1\ntwo 2\nseven 7\neight 8\ntwelve 12\nseventeen 17\n")?;
+        assert_eq!(expected, build(vocabulary).first_and_last(line));
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use std::path::Path;
+
+use aoc_parse::{final_parse, Failure, IResult, Span};
+use nom::bytes::complete::take_till1;
+use nom::character::complete::{digit1, line_ending, space1};
+use nom::combinator::cut;
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use nom::Parser;
+use nom_supreme::ParserExt;
+
+use crate::custom_error::AocError;
+
+/// Environment variable naming the vocabulary file the binaries use instead
+/// of English.
+pub const VOCABULARY_ENV: &str = "AOC_VOCABULARY";
+
+/// The spelled out words a calibration line may contain and the digits each
+/// stands for. A file has one `<word> <digits>` pair per line, like
+/// `seventeen 17`.
+///
+/// When several words start at the same place the longest one counts, and a
+/// word may start inside another: with `seven` and `seventeen`, `seventeen`
+/// is 17 and `eightwo` is 8 then 2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    /// Longest word first, so the first word that matches is the longest.
+    words: Vec<(String, Vec<u32>)>,
+}
+
+impl Vocabulary {
+    /// `one` to `nine`, from `vocabularies/english.txt`.
+    pub fn english() -> Self {
+        Self::parse(include_str!("../vocabularies/english.txt"))
+            .expect("the English vocabulary should parse")
+    }
+
+    /// The file in `AOC_VOCABULARY`, or English when it's not set.
+    pub fn from_env() -> Result<Self, AocError> {
+        match std::env::var_os(VOCABULARY_ENV) {
+            Some(path) => Self::load(path),
+            None => Ok(Self::english()),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        let error = |failure| AocError::parse_error(input, failure);
+        let entries = final_parse(entries, input).map_err(error)?;
+
+        let mut words: Vec<(String, Vec<u32>)> = Vec::with_capacity(entries.len());
+        for (word, digits) in entries {
+            if words.iter().any(|(known, _)| known == word.fragment()) {
+                return Err(error(Failure {
+                    span: (word.location_offset(), word.len()).into(),
+                    message: format!("`{}` is in the vocabulary twice", word.fragment()),
+                }));
+            }
+            let digits = digits.chars().filter_map(|c| c.to_digit(10)).collect();
+            words.push((word.fragment().to_string(), digits));
+        }
+        words.sort_by_key(|(word, _)| std::cmp::Reverse(word.len()));
+        Ok(Self { words })
+    }
+
+    /// Every word and its digits, longest word first.
+    pub fn words(&self) -> impl Iterator<Item = (&str, &[u32])> {
+        self.words
+            .iter()
+            .map(|(word, digits)| (word.as_str(), digits.as_slice()))
+    }
+
+    /// The digits of the longest word `rest` starts with.
+    pub fn spelled_at(&self, rest: &str) -> Option<&[u32]> {
+        self.words()
+            .find_map(|(word, digits)| rest.starts_with(word).then_some(digits))
+    }
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+fn entries(input: Span) -> IResult<Vec<(Span, Span)>> {
+    separated_list1(
+        line_ending,
+        separated_pair(
+            take_till1(char::is_whitespace),
+            cut(space1),
+            cut(digit1).context("digits"),
+        )
+        .context("word"),
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let vocabulary = Vocabulary::parse("seven 7\nseventeen 17\nfünf 5\n")?;
+        assert_eq!(Some(&[1, 7][..]), vocabulary.spelled_at("seventeenth"));
+        assert_eq!(Some(&[7][..]), vocabulary.spelled_at("seventy"));
+        assert_eq!(Some(&[5][..]), vocabulary.spelled_at("fünfzig"));
+        assert_eq!(None, vocabulary.spelled_at("six"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Vocabulary::parse("one one\n").is_err());
+        assert!(Vocabulary::parse("one 1\none 2\n").is_err());
+    }
+
+    #[test]
+    fn test_english() {
+        let vocabulary = Vocabulary::english();
+        assert_eq!(9, vocabulary.words().count());
+        assert_eq!(Some(&[8][..]), vocabulary.spelled_at("eightwo"));
+    }
+}
@@ -1,22 +1,31 @@
-use day_02::*;
+use aoc_solution::Solution;
+use y2023_day_02::Day02;
 
 fn main() {
     // Run registered benchmarks.
     divan::main();
 }
 
+fn read_input(part: u8) -> String {
+    aoc_input::Source::from_env()
+        .read(env!("CARGO_MANIFEST_DIR"), part)
+        .unwrap()
+}
+
+#[divan::bench]
+fn parse(bencher: divan::Bencher) {
+    let input = read_input(1);
+    bencher.bench(|| Day02::parse(divan::black_box(&input)).unwrap());
+}
+
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = Day02::parse(&read_input(1)).unwrap();
+    bencher.bench(|| Day02::part1(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
-}
\ No newline at end of file
+fn part2(bencher: divan::Bencher) {
+    let input = Day02::parse(&read_input(2)).unwrap();
+    bencher.bench(|| Day02::part2(divan::black_box(&input)).unwrap());
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl Color {
+    pub const ALL: [Color; 3] = [Color::Red, Color::Green, Color::Blue];
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Color::Red => "red",
+            Color::Green => "green",
+            Color::Blue => "blue",
+        })
+    }
+}
+
+/// A number of cubes of each color, what one draw shows or what a bag holds.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CubeSet {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl CubeSet {
+    pub const fn new(red: u32, green: u32, blue: u32) -> Self {
+        Self { red, green, blue }
+    }
+
+    pub fn get(&self, color: Color) -> u32 {
+        match color {
+            Color::Red => self.red,
+            Color::Green => self.green,
+            Color::Blue => self.blue,
+        }
+    }
+
+    pub fn get_mut(&mut self, color: Color) -> &mut u32 {
+        match color {
+            Color::Red => &mut self.red,
+            Color::Green => &mut self.green,
+            Color::Blue => &mut self.blue,
+        }
+    }
+
+    /// Whether `other` could be drawn out of this set.
+    pub fn contains(&self, other: &CubeSet) -> bool {
+        Color::ALL
+            .iter()
+            .all(|&color| other.get(color) <= self.get(color))
+    }
+
+    /// The smallest set containing both.
+    pub fn union(&self, other: &CubeSet) -> CubeSet {
+        CubeSet {
+            red: self.red.max(other.red),
+            green: self.green.max(other.green),
+            blue: self.blue.max(other.blue),
+        }
+    }
+
+    /// The counts multiplied together, `None` when that doesn't fit in 64
+    /// bits.
+    pub fn power(&self) -> Option<u64> {
+        u64::from(self.red)
+            .checked_mul(u64::from(self.green))?
+            .checked_mul(u64::from(self.blue))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<CubeSet>,
+}
+
+impl Game {
+    /// Whether every draw of the game could come out of `bag`.
+    pub fn is_feasible(&self, bag: &CubeSet) -> bool {
+        self.draws.iter().all(|draw| bag.contains(draw))
+    }
+
+    /// The smallest bag the game could have been played with.
+    pub fn get_game_minimum_cube_count(&self) -> CubeSet {
+        self.draws
+            .iter()
+            .fold(CubeSet::default(), |bag, draw| bag.union(draw))
+    }
+}
+
+/// How one color shows up in the draws of a list of games.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ColorStatistics {
+    /// Draws with at least one cube of the color.
+    pub draws: usize,
+    pub total: u64,
+    /// The most cubes of the color in a single draw.
+    pub max: u32,
+    /// The average number of cubes of the color per draw, over every draw.
+    pub mean: f64,
+}
+
+impl ColorStatistics {
+    pub fn of(games: &[Game], color: Color) -> Self {
+        let counts: Vec<u32> = games
+            .iter()
+            .flat_map(|game| game.draws.iter().map(|draw| draw.get(color)))
+            .collect();
+        let total = counts.iter().copied().map(u64::from).sum();
+        Self {
+            draws: counts.iter().filter(|&&count| count > 0).count(),
+            total,
+            max: counts.iter().copied().max().unwrap_or_default(),
+            mean: match counts.len() {
+                0 => 0.0,
+                len => total as f64 / len as f64,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game() -> Game {
+        Game {
+            id: 3,
+            draws: vec![
+                CubeSet::new(20, 8, 6),
+                CubeSet::new(4, 13, 5),
+                CubeSet::new(1, 5, 0),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_is_feasible() {
+        assert!(!game().is_feasible(&CubeSet::new(12, 13, 14)));
+        assert!(game().is_feasible(&CubeSet::new(20, 13, 6)));
+        assert!(!game().is_feasible(&CubeSet::new(20, 12, 6)));
+    }
+
+    #[test]
+    fn test_get_game_minimum_cube_count() {
+        let bag = game().get_game_minimum_cube_count();
+        assert_eq!(CubeSet::new(20, 13, 6), bag);
+        assert_eq!(Some(1560), bag.power());
+        assert_eq!(
+            Some(u64::from(u32::MAX) * u64::from(u32::MAX)),
+            CubeSet::new(u32::MAX, u32::MAX, 1).power()
+        );
+        assert_eq!(None, CubeSet::new(u32::MAX, u32::MAX, 2).power());
+    }
+
+    #[test]
+    fn test_color_statistics() {
+        let games = [game()];
+        assert_eq!(
+            ColorStatistics {
+                draws: 2,
+                total: 11,
+                max: 6,
+                mean: 11.0 / 3.0,
+            },
+            ColorStatistics::of(&games, Color::Blue)
+        );
+        assert_eq!(
+            ColorStatistics::default(),
+            ColorStatistics::of(&[], Color::Red)
+        );
+    }
+}
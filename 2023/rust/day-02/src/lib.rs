@@ -1,4 +1,32 @@
+use aoc_solution::Solution;
+
+use crate::custom_error::AocError;
+use crate::game::Game;
+
 pub mod custom_error;
+pub mod game;
+pub mod parser;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Vec<Game>, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(games: &Vec<Game>) -> Result<u32, AocError> {
+        part1::process(games)
+    }
+
+    fn part2(games: &Vec<Game>) -> Result<u64, AocError> {
+        part2::process(games)
+    }
+}
@@ -0,0 +1,129 @@
+use aoc_parse::{final_parse, IResult, Span};
+use nom::{
+    branch::alt,
+    character::complete::{self, line_ending, space1},
+    combinator::{cut, value},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+use thiserror::Error;
+
+use crate::custom_error::AocError;
+use crate::game::{Color, CubeSet, Game};
+
+#[tracing::instrument(skip_all)]
+pub fn parse(input: &str) -> miette::Result<Vec<Game>, AocError> {
+    final_parse(separated_list1(line_ending, game_parser), input)
+        .map_err(|error| AocError::parse_error(input, error))
+}
+
+/// Parses a set of cubes written like a draw, `12 red, 13 green, 14 blue`,
+/// e.g. to describe a bag.
+pub fn parse_cube_set(input: &str) -> miette::Result<CubeSet, AocError> {
+    final_parse(cube_set_parser, input).map_err(|error| AocError::parse_error(input, error))
+}
+
+fn game_parser(input: Span) -> IResult<Game> {
+    let (input, id) = preceded(tag("Game "), cut(complete::u32))
+        .context("game id")
+        .parse(input)?;
+    let (input, _) = cut(tag(": "))(input)?;
+    let (input, draws) = cut(separated_list1(tag("; "), cube_set_parser))
+        .context("draws")
+        .parse(input)?;
+
+    Ok((input, Game { id, draws }))
+}
+
+/// More cubes of one color in a set than a `u32` counts.
+#[derive(Debug, Error)]
+#[error("more than {} {color} cubes", u32::MAX)]
+struct TooManyCubes {
+    color: Color,
+}
+
+/// A color may be listed twice, its counts add up.
+fn cube_set_parser(input: Span) -> IResult<CubeSet> {
+    let cubes = separated_pair(complete::u32, space1, cut(color_parser)).context("cubes");
+    separated_list1(tag(", "), cubes)
+        .map_res_cut(|cubes| {
+            cubes
+                .into_iter()
+                .try_fold(CubeSet::default(), |mut set, (count, color)| {
+                    let total = set.get_mut(color);
+                    *total = total.checked_add(count).ok_or(TooManyCubes { color })?;
+                    Ok::<_, TooManyCubes>(set)
+                })
+        })
+        .parse(input)
+}
+
+fn color_parser(input: Span) -> IResult<Color> {
+    alt((
+        value(Color::Red, tag("red")),
+        value(Color::Green, tag("green")),
+        value(Color::Blue, tag("blue")),
+    ))
+    .context("color")
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let games = parse(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 12: 1 blue, 2 blue",
+        )?;
+        assert_eq!(
+            vec![
+                Game {
+                    id: 1,
+                    draws: vec![
+                        CubeSet::new(4, 0, 3),
+                        CubeSet::new(1, 2, 6),
+                        CubeSet::new(0, 2, 0)
+                    ],
+                },
+                Game {
+                    id: 12,
+                    draws: vec![CubeSet::new(0, 0, 3)],
+                },
+            ],
+            games
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(parse("Game 1: 3 purple").is_err());
+        assert!(parse("Game one: 3 red").is_err());
+        assert!(parse("Game 1: 3 red4 blue").is_err());
+        assert!(parse("Game 1: 3 red, ; 2 blue").is_err());
+    }
+
+    #[test]
+    fn test_parse_too_many_cubes() {
+        let input = "Game 1: 1 blue; 4294967295 red, 1 red";
+        assert!(matches!(
+            parse(input),
+            Err(AocError::ParseError { span, message, .. })
+                if span.offset() == 16 && message.starts_with("more than 4294967295 red cubes")
+        ));
+    }
+
+    #[test]
+    fn test_parse_cube_set() -> miette::Result<()> {
+        assert_eq!(
+            CubeSet::new(12, 13, 14),
+            parse_cube_set("12 red, 13 green, 14 blue")?
+        );
+        Ok(())
+    }
+}
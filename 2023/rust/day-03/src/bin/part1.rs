@@ -1,5 +1,9 @@
-use day_03::part1::process;
+use aoc_solution::Solution;
 use miette::Context;
+#[cfg(feature = "tracy")]
+use tracing_subscriber::layer::SubscriberExt;
+use y2023_day_03::custom_error::AocError;
+use y2023_day_03::Day03;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
@@ -10,11 +14,21 @@ fn main() -> miette::Result<()> {
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
 
-    #[cfg(not(feature = "dhat-heap"))]
+    #[cfg(feature = "tracy")]
+    tracing::subscriber::set_global_default(
+        tracing_subscriber::registry().with(tracing_tracy::TracyLayer::new()),
+    )
+    .expect("no other subscriber is set");
+
+    #[cfg(not(any(feature = "dhat-heap", feature = "tracy")))]
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input1.txt");
-    let result = process(file).context("process part 1")?;
+    let file = aoc_input::Source::from_args()
+        .read(env!("CARGO_MANIFEST_DIR"), 1)
+        .map_err(AocError::from)
+        .context("read input")?;
+    let input = Day03::parse(&file).context("parse input")?;
+    let result = Day03::part1(&input).context("process part 1")?;
     println!("{}", result);
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,4 +1,32 @@
+use aoc_solution::Solution;
+
+use crate::custom_error::AocError;
+use crate::schematic::Schematic;
+
 pub mod custom_error;
+pub mod parser;
+pub mod schematic;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+    type Answer1 = u32;
+    type Answer2 = u32;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Schematic, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(schematic: &Schematic) -> Result<u32, AocError> {
+        part1::process(schematic)
+    }
+
+    fn part2(schematic: &Schematic) -> Result<u32, AocError> {
+        part2::process(schematic)
+    }
+}
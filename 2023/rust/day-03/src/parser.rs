@@ -0,0 +1,50 @@
+use aoc_grid::Grid;
+
+use crate::custom_error::AocError;
+use crate::schematic::{NumberOverflow, Schematic};
+
+#[tracing::instrument(skip_all)]
+pub fn parse(input: &str) -> miette::Result<Schematic, AocError> {
+    let grid = Grid::parse_chars(input)?;
+    Schematic::index(&grid).map_err(|overflow| overflow_error(input, overflow))
+}
+
+/// Points at the digits of the number that overflowed.
+fn overflow_error(input: &str, NumberOverflow { start }: NumberOverflow) -> AocError {
+    // The grid skips the empty lines before it.
+    let line = input
+        .lines()
+        .skip_while(|line| line.is_empty())
+        .nth(start.y as usize)
+        .expect("the number is on the grid");
+    let (column, _) = line
+        .char_indices()
+        .nth(start.x as usize)
+        .expect("the number is on the grid");
+    let digits = line[column..]
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(line.len() - column);
+    AocError::ParseError {
+        input: input.to_string(),
+        // `lines` borrows from `input`, so the pointers give the offset.
+        span: (
+            line.as_ptr() as usize - input.as_ptr() as usize + column,
+            digits,
+        )
+            .into(),
+        message: "number doesn't fit in 32 bits".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_overflow() {
+        assert!(matches!(
+            parse("\n..4294967295*\n.42949672950."),
+            Err(AocError::ParseError { span, .. }) if span == (16, 11).into()
+        ));
+    }
+}
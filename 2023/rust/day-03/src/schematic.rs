@@ -0,0 +1,277 @@
+use aoc_grid::{Coordinates, Grid};
+use itertools::Itertools;
+
+/// A number of the schematic, written left to right from `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub start: Coordinates,
+    pub len: usize,
+}
+
+impl Number {
+    /// The cells the digits are written in.
+    pub fn cells(&self) -> impl Iterator<Item = Coordinates> {
+        let start = self.start;
+        (0..self.len as i32).map(move |dx| Coordinates::new(start.x + dx, start.y))
+    }
+}
+
+/// A number of the schematic with too many digits for a `u32`, by where it
+/// starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberOverflow {
+    pub start: Coordinates,
+}
+
+/// Anything that is neither a digit nor a `.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: char,
+    pub coordinates: Coordinates,
+}
+
+/// What a cell of the schematic belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Item<'a> {
+    Number(&'a Number),
+    Symbol(&'a Symbol),
+}
+
+/// Index of [`Schematic::numbers`] or [`Schematic::symbols`] for every cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Cell {
+    Empty,
+    Number(usize),
+    Symbol(usize),
+}
+
+/// The engine schematic, indexed so that every cell knows which number or
+/// symbol it is part of and adjacency, diagonals included, is a lookup.
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    cells: Grid<Cell>,
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+}
+
+impl Schematic {
+    pub fn index(grid: &Grid<char>) -> Result<Self, NumberOverflow> {
+        let mut cells = Grid::new(grid.width(), grid.height(), Cell::Empty);
+        let mut numbers: Vec<Number> = Vec::new();
+        let mut symbols = Vec::new();
+
+        for (coordinates, &c) in grid.iter() {
+            if let Some(digit) = c.to_digit(10) {
+                let previous = Coordinates::new(coordinates.x - 1, coordinates.y);
+                match cells.get(&previous) {
+                    Some(&Cell::Number(id)) => {
+                        let number = &mut numbers[id];
+                        number.value = number
+                            .value
+                            .checked_mul(10)
+                            .and_then(|value| value.checked_add(digit))
+                            .ok_or(NumberOverflow {
+                                start: number.start,
+                            })?;
+                        number.len += 1;
+                    }
+                    _ => numbers.push(Number {
+                        value: digit,
+                        start: coordinates,
+                        len: 1,
+                    }),
+                }
+                cells[coordinates] = Cell::Number(numbers.len() - 1);
+            } else if c != '.' {
+                symbols.push(Symbol {
+                    symbol: c,
+                    coordinates,
+                });
+                cells[coordinates] = Cell::Symbol(symbols.len() - 1);
+            }
+        }
+
+        Ok(Self {
+            cells,
+            numbers,
+            symbols,
+        })
+    }
+
+    /// Every number, row by row.
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    /// Every symbol, row by row.
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// The number or symbol at `coordinates`, `None` on a `.` or off the
+    /// schematic.
+    pub fn at(&self, coordinates: &Coordinates) -> Option<Item<'_>> {
+        self.cells
+            .get(coordinates)
+            .and_then(|&cell| self.item(cell))
+    }
+
+    /// The distinct numbers touching `symbol`.
+    pub fn numbers_around(&self, symbol: &Symbol) -> Vec<&Number> {
+        self.around([symbol.coordinates])
+            .filter_map(|item| match item {
+                Item::Number(number) => Some(number),
+                Item::Symbol(_) => None,
+            })
+            .collect()
+    }
+
+    /// The symbols touching `number`.
+    pub fn symbols_around(&self, number: &Number) -> Vec<&Symbol> {
+        self.around(number.cells())
+            .filter_map(|item| match item {
+                Item::Symbol(symbol) => Some(symbol),
+                Item::Number(_) => None,
+            })
+            .collect()
+    }
+
+    /// The numbers touching at least one symbol `symbol` is true for, each
+    /// once, in the order they are written.
+    pub fn numbers_next_to(&self, symbol: impl Fn(char) -> bool) -> Vec<&Number> {
+        self.numbers
+            .iter()
+            .filter(|number| {
+                self.symbols_around(number)
+                    .iter()
+                    .any(|around| symbol(around.symbol))
+            })
+            .collect()
+    }
+
+    /// The `symbol`s touching exactly `count` numbers, with those numbers.
+    pub fn gears(
+        &self,
+        symbol: char,
+        count: usize,
+    ) -> impl Iterator<Item = (&Symbol, Vec<&Number>)> + '_ {
+        self.symbols
+            .iter()
+            .filter(move |gear| gear.symbol == symbol)
+            .map(|gear| (gear, self.numbers_around(gear)))
+            .filter(move |(_, numbers)| numbers.len() == count)
+    }
+
+    /// The distinct items in the cells surrounding `cells`, `cells`
+    /// themselves excluded.
+    fn around(
+        &self,
+        cells: impl IntoIterator<Item = Coordinates>,
+    ) -> impl Iterator<Item = Item<'_>> {
+        let cells: Vec<Coordinates> = cells.into_iter().collect();
+        let ids: Vec<Cell> = cells
+            .iter()
+            .flat_map(|coordinates| self.cells.neighbours8(coordinates))
+            .filter(|(coordinates, _)| !cells.contains(coordinates))
+            .map(|(_, &cell)| cell)
+            .unique()
+            .collect();
+        ids.into_iter().filter_map(|cell| self.item(cell))
+    }
+
+    fn item(&self, cell: Cell) -> Option<Item<'_>> {
+        match cell {
+            Cell::Empty => None,
+            Cell::Number(id) => Some(Item::Number(&self.numbers[id])),
+            Cell::Symbol(id) => Some(Item::Symbol(&self.symbols[id])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMATIC: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......";
+
+    fn schematic() -> Schematic {
+        Schematic::index(&Grid::parse_chars(SCHEMATIC).unwrap()).unwrap()
+    }
+
+    fn values(numbers: Vec<&Number>) -> Vec<u32> {
+        numbers.iter().map(|number| number.value).collect()
+    }
+
+    #[test]
+    fn test_index() {
+        let schematic = schematic();
+        assert_eq!(5, schematic.numbers().len());
+        assert_eq!(3, schematic.symbols().len());
+        assert_eq!(
+            Some(Item::Number(&Number {
+                value: 467,
+                start: Coordinates::new(0, 0),
+                len: 3,
+            })),
+            schematic.at(&Coordinates::new(2, 0))
+        );
+        assert_eq!(None, schematic.at(&Coordinates::new(3, 0)));
+        assert_eq!(None, schematic.at(&Coordinates::new(-1, 0)));
+    }
+
+    #[test]
+    fn test_index_overflow() {
+        let grid = Grid::parse_chars("..4294967295*\n.42949672950.").unwrap();
+        assert_eq!(
+            Err(NumberOverflow {
+                start: Coordinates::new(1, 1)
+            }),
+            Schematic::index(&grid).map(|schematic| schematic.numbers().len())
+        );
+    }
+
+    #[test]
+    fn test_adjacency() {
+        let schematic = schematic();
+        let star = &schematic.symbols()[0];
+        assert_eq!(vec![467, 35], values(schematic.numbers_around(star)));
+
+        let number = &schematic.numbers()[3];
+        assert_eq!(633, number.value);
+        let symbols = schematic.symbols_around(number);
+        assert_eq!(
+            vec!['#'],
+            symbols
+                .iter()
+                .map(|symbol| symbol.symbol)
+                .collect::<Vec<_>>()
+        );
+        assert!(schematic.symbols_around(&schematic.numbers()[1]).is_empty());
+    }
+
+    #[test]
+    fn test_numbers_next_to() {
+        let schematic = schematic();
+        assert_eq!(
+            vec![467, 35, 633, 617],
+            values(schematic.numbers_next_to(|_| true))
+        );
+        assert_eq!(vec![633], values(schematic.numbers_next_to(|c| c == '#')));
+    }
+
+    #[test]
+    fn test_gears() {
+        let schematic = schematic();
+        let gears: Vec<Vec<u32>> = schematic
+            .gears('*', 2)
+            .map(|(_, numbers)| values(numbers))
+            .collect();
+        assert_eq!(vec![vec![467, 35]], gears);
+        assert_eq!(1, schematic.gears('*', 1).count());
+    }
+}
@@ -1,5 +1,10 @@
-use day_04::part2::process;
+use aoc_solution::Solution;
 use miette::Context;
+#[cfg(feature = "tracy")]
+use tracing_subscriber::layer::SubscriberExt;
+use y2023_day_04::cascade::{Cascade, REPORT_ENV};
+use y2023_day_04::custom_error::AocError;
+use y2023_day_04::Day04;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
@@ -10,11 +15,32 @@ fn main() -> miette::Result<()> {
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
 
-    #[cfg(not(feature = "dhat-heap"))]
+    #[cfg(feature = "tracy")]
+    tracing::subscriber::set_global_default(
+        tracing_subscriber::registry().with(tracing_tracy::TracyLayer::new()),
+    )
+    .expect("no other subscriber is set");
+
+    #[cfg(not(any(feature = "dhat-heap", feature = "tracy")))]
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 2")?;
+    let file = aoc_input::Source::from_args()
+        .read(env!("CARGO_MANIFEST_DIR"), 2)
+        .map_err(AocError::from)
+        .context("read input")?;
+    let input = Day04::parse(&file).context("parse input")?;
+    let result = match std::env::var_os(REPORT_ENV) {
+        // The answer is the total of the cascade the report is written from.
+        Some(path) => {
+            let cascade = Cascade::run(&input).context("run the cascade")?;
+            std::fs::File::create(path)
+                .and_then(|file| cascade.write_csv(file))
+                .map_err(AocError::from)
+                .context("write cascade report")?;
+            cascade.total().context("process part 2")?
+        }
+        None => Day04::part2(&input).context("process part 2")?,
+    };
     println!("{}", result);
     Ok(())
 }
@@ -0,0 +1,66 @@
+use std::collections::BTreeSet;
+
+use crate::custom_error::AocError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub id: u32,
+    pub winning: BTreeSet<u32>,
+    pub numbers: BTreeSet<u32>,
+}
+
+impl Card {
+    /// How many of the numbers are winning ones.
+    pub fn matches(&self) -> usize {
+        self.winning.intersection(&self.numbers).count()
+    }
+
+    /// 1 point for the first match, doubled for every other one.
+    pub fn points(&self) -> Result<u64, AocError> {
+        match self.matches() {
+            0 => Ok(0),
+            matches => u32::try_from(matches - 1)
+                .ok()
+                .and_then(|shift| 1u64.checked_shl(shift))
+                .ok_or(AocError::PointsOverflow { card: self.id }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_points() -> miette::Result<()> {
+        let card = Card {
+            id: 1,
+            winning: BTreeSet::from([41, 48, 83, 86, 17]),
+            numbers: BTreeSet::from([83, 86, 6, 31, 17, 9, 48, 53]),
+        };
+        assert_eq!(4, card.matches());
+        assert_eq!(8, card.points()?);
+
+        let card = Card {
+            numbers: BTreeSet::from([1, 2]),
+            ..card
+        };
+        assert_eq!(0, card.points()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_points_overflow() -> miette::Result<()> {
+        let card = |matches| Card {
+            id: 7,
+            winning: (1..=matches).collect(),
+            numbers: (1..=matches).collect(),
+        };
+        assert_eq!(1 << 63, card(64).points()?);
+        assert!(matches!(
+            card(65).points(),
+            Err(AocError::PointsOverflow { card: 7 })
+        ));
+        Ok(())
+    }
+}
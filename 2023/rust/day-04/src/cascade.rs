@@ -0,0 +1,209 @@
+use std::fmt;
+use std::io;
+
+use crate::card::Card;
+use crate::custom_error::AocError;
+
+/// Environment variable naming a file the part 2 binary writes the cascade
+/// to, as CSV.
+pub const REPORT_ENV: &str = "AOC_REPORT";
+
+/// How one card fared once every copy has been scratched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardReport {
+    pub id: u32,
+    pub matches: usize,
+    pub points: u64,
+    /// The original and the copies won from earlier cards.
+    pub instances: u64,
+    /// The earlier cards the copies came from, by id, and how many each
+    /// gave.
+    pub won_from: Vec<(u32, u64)>,
+}
+
+impl CardReport {
+    /// Copies won from earlier cards, the original not included.
+    pub fn copies(&self) -> u64 {
+        self.instances - 1
+    }
+
+    fn won_from(&self) -> String {
+        self.won_from
+            .iter()
+            .map(|(id, copies)| format!("{id}x{copies}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Scratchcards winning copies of the cards below them: every instance of a
+/// card with `n` matches wins one copy of each of the next `n` cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cascade {
+    pub cards: Vec<CardReport>,
+}
+
+impl Cascade {
+    /// Plays `cards` in order. Copies past the last card are lost. The
+    /// number of copies grows exponentially, a variant that doesn't fit in
+    /// 64 bits is an error.
+    #[tracing::instrument(skip_all)]
+    pub fn run(cards: &[Card]) -> Result<Self, AocError> {
+        let mut reports = cards
+            .iter()
+            .map(|card| {
+                Ok(CardReport {
+                    id: card.id,
+                    matches: card.matches(),
+                    points: card.points()?,
+                    instances: 1,
+                    won_from: Vec::new(),
+                })
+            })
+            .collect::<Result<Vec<CardReport>, AocError>>()?;
+
+        for index in 0..reports.len() {
+            let (played, below) = reports.split_at_mut(index + 1);
+            let card = &played[index];
+            for won in below.iter_mut().take(card.matches) {
+                won.instances = won
+                    .instances
+                    .checked_add(card.instances)
+                    .ok_or(AocError::CopiesOverflow { card: won.id })?;
+                won.won_from.push((card.id, card.instances));
+            }
+            tracing::trace!(card.id, card.instances, "played");
+        }
+
+        Ok(Self { cards: reports })
+    }
+
+    /// Every instance of every card.
+    pub fn total(&self) -> Result<u64, AocError> {
+        self.cards.iter().try_fold(0u64, |total, card| {
+            total
+                .checked_add(card.instances)
+                .ok_or(AocError::CopiesOverflow { card: card.id })
+        })
+    }
+
+    /// The report as CSV, one row per card.
+    pub fn write_csv(&self, mut writer: impl io::Write) -> io::Result<()> {
+        writeln!(writer, "card,matches,points,copies,instances,won_from")?;
+        for card in &self.cards {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{}",
+                card.id,
+                card.matches,
+                card.points,
+                card.copies(),
+                card.instances,
+                card.won_from()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Cascade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>4}  {:>7}  {:>6}  {:>9}  won from",
+            "card", "matches", "points", "instances"
+        )?;
+        for card in &self.cards {
+            let row = format!(
+                "{:>4}  {:>7}  {:>6}  {:>9}  {}",
+                card.id,
+                card.matches,
+                card.points,
+                card.instances,
+                card.won_from()
+            );
+            write!(f, "\n{}", row.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+
+    const CARDS: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn test_run() -> miette::Result<()> {
+        let cascade = Cascade::run(&parse(CARDS)?)?;
+        let instances: Vec<u64> = cascade.cards.iter().map(|card| card.instances).collect();
+        assert_eq!(vec![1, 2, 4, 8, 14, 1], instances);
+        assert_eq!(
+            CardReport {
+                id: 4,
+                matches: 1,
+                points: 1,
+                instances: 8,
+                won_from: vec![(1, 1), (2, 2), (3, 4)],
+            },
+            cascade.cards[3]
+        );
+        assert_eq!(30, cascade.total()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_csv() -> miette::Result<()> {
+        let cascade = Cascade::run(&parse(CARDS)?)?;
+        let mut csv = Vec::new();
+        cascade.write_csv(&mut csv).expect("writing to a Vec");
+        let csv = String::from_utf8(csv).expect("CSV is UTF-8");
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!("card,matches,points,copies,instances,won_from", rows[0]);
+        assert_eq!("1,4,8,0,1,", rows[1]);
+        assert_eq!("5,0,0,13,14,1x1 3x4 4x8", rows[5]);
+        assert_eq!(7, rows.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_overflow() -> miette::Result<()> {
+        // Every card wins a copy of all the cards below it, so card n has
+        // 2^(n - 1) instances and card 65 is past what 64 bits can count.
+        let cards: Vec<Card> = (1..=66)
+            .map(|id| Card {
+                id,
+                winning: (id..=66).collect(),
+                numbers: (id..=66).collect(),
+            })
+            .collect();
+        assert!(matches!(
+            Cascade::run(&cards[..3]),
+            Err(AocError::PointsOverflow { card: 1 })
+        ));
+
+        let cards: Vec<Card> = cards
+            .into_iter()
+            .map(|card| Card {
+                winning: card.winning.into_iter().take(64).collect(),
+                numbers: card.numbers.into_iter().take(64).collect(),
+                ..card
+            })
+            .collect();
+        let cascade = Cascade::run(&cards[..64])?;
+        assert_eq!(1 << 63, cascade.cards[63].instances);
+        assert_eq!(u64::MAX, cascade.total()?);
+        assert!(matches!(
+            Cascade::run(&cards),
+            Err(AocError::CopiesOverflow { card: 65 })
+        ));
+        Ok(())
+    }
+}
@@ -1,5 +1,4 @@
-
-use miette::Diagnostic;
+use miette::{Diagnostic, SourceSpan};
 use thiserror::Error;
 
 #[derive(Error, Diagnostic, Debug)]
@@ -7,4 +6,33 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
-}
\ No newline at end of file
+
+    #[error("failed to parse input")]
+    #[diagnostic(code(aoc::parse_error))]
+    ParseError {
+        #[source_code]
+        input: String,
+        #[label("{message}")]
+        span: SourceSpan,
+        message: String,
+    },
+
+    #[error("the points of card {card} don't fit in 64 bits")]
+    #[diagnostic(code(aoc::points_overflow))]
+    PointsOverflow { card: u32 },
+
+    #[error("the copies of card {card} don't fit in 64 bits")]
+    #[diagnostic(code(aoc::copies_overflow))]
+    CopiesOverflow { card: u32 },
+}
+
+impl AocError {
+    pub fn parse_error(input: &str, error: impl aoc_parse::Locate) -> Self {
+        let failure = error.locate(input);
+        AocError::ParseError {
+            input: input.to_string(),
+            span: failure.span,
+            message: failure.message,
+        }
+    }
+}
@@ -1,4 +1,33 @@
+use aoc_solution::Solution;
+
+use crate::card::Card;
+use crate::custom_error::AocError;
+
+pub mod card;
+pub mod cascade;
 pub mod custom_error;
+pub mod parser;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Vec<Card>, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(cards: &Vec<Card>) -> Result<u64, AocError> {
+        part1::process(cards)
+    }
+
+    fn part2(cards: &Vec<Card>) -> Result<u64, AocError> {
+        part2::process(cards)
+    }
+}
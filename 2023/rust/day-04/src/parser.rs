@@ -0,0 +1,74 @@
+use std::collections::BTreeSet;
+
+use aoc_parse::{final_parse, IResult, Span};
+use nom::{
+    character::complete::{self, line_ending, space0, space1},
+    combinator::cut,
+    multi::separated_list1,
+    sequence::{delimited, preceded, tuple},
+    Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+
+use crate::card::Card;
+use crate::custom_error::AocError;
+
+#[tracing::instrument(skip_all)]
+pub fn parse(input: &str) -> miette::Result<Vec<Card>, AocError> {
+    final_parse(separated_list1(line_ending, card_parser), input)
+        .map_err(|error| AocError::parse_error(input, error))
+}
+
+fn card_parser(input: Span) -> IResult<Card> {
+    let (input, id) = preceded(tuple((tag("Card"), space1)), cut(complete::u32))
+        .context("card id")
+        .parse(input)?;
+    let (input, winning) = preceded(cut(tag(":")), cut(numbers_parser))
+        .context("winning numbers")
+        .parse(input)?;
+    let (input, numbers) = preceded(cut(tag("|")), cut(numbers_parser))
+        .context("numbers")
+        .parse(input)?;
+
+    Ok((
+        input,
+        Card {
+            id,
+            winning,
+            numbers,
+        },
+    ))
+}
+
+fn numbers_parser(input: Span) -> IResult<BTreeSet<u32>> {
+    delimited(space0, separated_list1(space1, complete::u32), space0)
+        .map(BTreeSet::from_iter)
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let cards = parse("Card 1: 41 48 | 83 86  6\nCard  12:  1 21 |  1 2")?;
+        assert_eq!(
+            vec![
+                Card {
+                    id: 1,
+                    winning: BTreeSet::from([41, 48]),
+                    numbers: BTreeSet::from([83, 86, 6]),
+                },
+                Card {
+                    id: 12,
+                    winning: BTreeSet::from([1, 21]),
+                    numbers: BTreeSet::from([1, 2]),
+                },
+            ],
+            cards
+        );
+        assert!(parse("Card 1: 41 48 83").is_err());
+        Ok(())
+    }
+}
@@ -1,4 +1,31 @@
+use aoc_solution::Solution;
+
+use crate::custom_error::AocError;
+use crate::parser::Almanac;
+
 pub mod custom_error;
+pub mod parser;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Almanac, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(almanac: &Almanac) -> Result<u64, AocError> {
+        part1::process(almanac)
+    }
+
+    fn part2(almanac: &Almanac) -> Result<u64, AocError> {
+        part2::process(almanac)
+    }
+}
@@ -0,0 +1,136 @@
+use aoc_parse::{final_parse, IResult, Span};
+use nom::bytes::complete::is_not;
+use nom::character::complete::{self, line_ending, multispace1, space1};
+use nom::combinator::cut;
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{preceded, terminated, tuple};
+use nom::Parser;
+use nom_supreme::tag::complete::tag;
+use nom_supreme::ParserExt;
+
+use std::ops::Range;
+
+use crate::custom_error::AocError;
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub conversion_maps: Vec<ConversionMap>,
+}
+
+impl Almanac {
+    /// Runs `seed` through every conversion map, down to its location.
+    pub fn location(&self, seed: u64) -> u64 {
+        self.conversion_maps
+            .iter()
+            .fold(seed, |acc, conversion_map| {
+                let mapping_to_use = conversion_map
+                    .iter()
+                    .find(|mapping| mapping.src.contains(&acc));
+
+                match mapping_to_use {
+                    Some(mapping) => mapping.translate(acc),
+                    None => acc,
+                }
+            })
+    }
+}
+
+pub type ConversionMap = Vec<Mapping>;
+
+#[derive(Debug)]
+pub struct Mapping {
+    pub src: Range<u64>,
+    pub dst: Range<u64>,
+}
+
+impl Mapping {
+    pub fn translate(&self, elem: u64) -> u64 {
+        self.dst.start + (elem - self.src.start)
+    }
+}
+
+#[tracing::instrument(skip_all)]
+pub fn parse(input: &str) -> miette::Result<Almanac, AocError> {
+    final_parse(parse_input, input).map_err(|error| AocError::parse_error(input, error))
+}
+
+fn parse_input(input: Span) -> IResult<Almanac> {
+    let (input, seeds) = preceded(
+        tag("seeds: "),
+        cut(separated_list1(complete::space1, complete::u64)),
+    )
+    .context("seeds")
+    .parse(input)?;
+
+    let (input, conversion_maps) = many1(preceded(multispace1, conversion_map_parser))(input)?;
+
+    Ok((
+        input,
+        Almanac {
+            seeds,
+            conversion_maps,
+        },
+    ))
+}
+
+fn conversion_map_parser(input: Span) -> IResult<ConversionMap> {
+    let (input, _) = terminated(is_not(" \r\n"), tag(" map:"))
+        .context("map header")
+        .parse(input)?;
+    let (input, _) = cut(line_ending)(input)?;
+    let (input, conversion_map) = cut(separated_list1(line_ending, mapping_parser))(input)?;
+
+    Ok((input, conversion_map))
+}
+
+fn mapping_parser(input: Span) -> IResult<Mapping> {
+    let (input, (dst, src, length)) = tuple((
+        complete::u64,
+        cut(preceded(space1, complete::u64)),
+        cut(preceded(space1, complete::u64)),
+    ))
+    .context("mapping")
+    .parse(input)?;
+
+    let mapping = Mapping {
+        src: src..(src + length),
+        dst: dst..(dst + length),
+    };
+
+    Ok((input, mapping))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let almanac = parse(
+            "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+52 50 48",
+        )?;
+        assert_eq!(vec![79, 14], almanac.seeds);
+        assert_eq!(81, almanac.location(79));
+        assert_eq!(14, almanac.location(14));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 x0 48";
+        let Err(AocError::ParseError { span, message, .. }) = parse(input) else {
+            panic!("a malformed mapping should not parse");
+        };
+        assert_eq!(input.find("x0").unwrap(), span.offset());
+        assert_eq!("expected an ascii digit in mapping", message);
+    }
+}
@@ -1,4 +1,31 @@
+use aoc_solution::Solution;
+
+use crate::custom_error::AocError;
+use crate::parser::Race;
+
 pub mod custom_error;
+pub mod parser;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Race>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Vec<Race>, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(races: &Vec<Race>) -> Result<usize, AocError> {
+        part1::process(races)
+    }
+
+    fn part2(races: &Vec<Race>) -> Result<usize, AocError> {
+        part2::process(races)
+    }
+}
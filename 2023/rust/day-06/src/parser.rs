@@ -0,0 +1,66 @@
+use aoc_parse::final_parse_str;
+use nom::{
+    bytes::complete::tag, character::complete, multi::separated_list1, sequence::preceded, IResult,
+};
+
+use crate::custom_error::AocError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+impl Race {
+    pub fn get_winning_button_hold_times(&self) -> Vec<u64> {
+        (1..self.time)
+            .map(|pressed_time| self.distance_from_pressed_time(pressed_time))
+            .filter(|distance| *distance > self.distance)
+            .collect()
+    }
+
+    fn distance_from_pressed_time(&self, pressed_time: u64) -> u64 {
+        (self.time - pressed_time) * pressed_time
+    }
+}
+
+#[tracing::instrument(skip_all)]
+pub fn parse(input: &str) -> miette::Result<Vec<Race>, AocError> {
+    final_parse_str(parse_input, input).map_err(|error| AocError::parse_error(input, error))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Race>> {
+    let (input, times) = preceded(tag("Time:"), list_parser)(input)?;
+    let (input, _) = complete::line_ending(input)?;
+    let (input, distances) = preceded(tag("Distance:"), list_parser)(input)?;
+
+    let races = times
+        .iter()
+        .zip(distances.iter())
+        .map(|(time, distance)| Race {
+            time: *time,
+            distance: *distance,
+        })
+        .collect();
+
+    Ok((input, races))
+}
+
+fn list_parser(input: &str) -> IResult<&str, Vec<u64>> {
+    let (input, _) = complete::space1(input)?;
+    let (input, numbers) = separated_list1(complete::space1, complete::u64)(input)?;
+    Ok((input, numbers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert!(matches!(
+            parse("Time: 7 15\nDistance: 9 40\nTime: 3\n"),
+            Err(AocError::ParseError { span, .. }) if span.offset() == 26
+        ));
+    }
+}
@@ -1,63 +1,42 @@
-use nom::{
-    bytes::complete::tag, character::complete, multi::separated_list1, sequence::preceded, IResult,
-};
-
 use crate::custom_error::AocError;
+use crate::parser::Race;
 
-#[derive(Debug)]
-struct Race {
-    time: u64,
-    distance: u64,
-}
-
-impl Race {
-    fn get_winning_button_hold_times(&self) -> Vec<u64> {
-        (1..self.time)
-            .map(|pressed_time| self.distance_from_pressed_time(pressed_time))
-            .filter(|distance| *distance > self.distance)
-            .collect()
-    }
-
-    fn distance_from_pressed_time(&self, pressed_time: u64) -> u64 {
-        (self.time - pressed_time) * pressed_time
-    }
-}
-
-#[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String, AocError> {
-    let (_, race) = parse_input(_input).unwrap();
+#[tracing::instrument(skip_all)]
+pub fn process(races: &[Race]) -> miette::Result<usize, AocError> {
+    let race = Race {
+        time: concat(races.iter().map(|race| race.time)),
+        distance: concat(races.iter().map(|race| race.distance)),
+    };
 
-    let winning_solutions = race.get_winning_button_hold_times().len();
-
-    Ok(winning_solutions.to_string())
-}
-
-fn parse_input(input: &str) -> IResult<&str, Race> {
-    let (input, time) = preceded(tag("Time:"), list_parser)(input)?;
-    let (input, _) = complete::line_ending(input)?;
-    let (input, distance) = preceded(tag("Distance:"), list_parser)(input)?;
-
-    let race = Race { time, distance };
-
-    Ok((input, race))
+    Ok(race.get_winning_button_hold_times().len())
 }
 
-fn list_parser(input: &str) -> IResult<&str, u64> {
-    let (input, _) = complete::space1(input)?;
-    let (input, numbers) = separated_list1(complete::space1, complete::digit1)(input)?;
-    let numbers = numbers.concat().parse::<u64>().unwrap();
-    Ok((input, numbers))
+/// Joins the digits of `numbers`, the kerning on the sheet of paper was bad.
+fn concat(numbers: impl Iterator<Item = u64>) -> u64 {
+    numbers.fold(0, |acc, number| {
+        acc * 10u64.pow(number.checked_ilog10().unwrap_or(0) + 1) + number
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use std::path::PathBuf;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "Time:      7  15   30
-Distance:  9  40  200";
-        assert_eq!("71503", process(input)?);
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::parser::parse;
+
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part2.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(
+            fixture.expected,
+            process(&parse(&fixture.input)?)?.to_string()
+        );
         Ok(())
     }
 }
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use aoc_parse::final_parse_str;
 use itertools::Itertools;
 use nom::{
     bytes::complete::is_a,
@@ -106,16 +107,17 @@ fn get_value_from_card(card: char) -> u32 {
     }
 }
 
-#[tracing::instrument]
+#[tracing::instrument(skip_all)]
 pub fn process(_input: &str) -> miette::Result<String, AocError> {
-    let (_, hands) = parse_input(_input).unwrap();
+    let hands = final_parse_str(parse_input, _input)
+        .map_err(|error| AocError::parse_error(_input, error))?;
 
     let winnings: u64 = hands
         .iter()
         .sorted()
         .enumerate()
         .map(|(i, hand)| {
-            println!("Rank: {:}, Hand: {:?}", i + 1, hand);
+            tracing::trace!(rank = i + 1, ?hand);
             hand.bid * (i as u64 + 1)
         })
         .sum();
@@ -134,16 +136,27 @@ fn parse_hand(input: &str) -> IResult<&str, Hand> {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
     use super::*;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
-        assert_eq!("6440", process(input)?);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part1.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process(&fixture.input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_process_parse_error() {
+        assert!(matches!(
+            process("32T3K 765\nT55J5 x\nKK677 28\n"),
+            Err(AocError::ParseError { span, .. }) if span.offset() == 10
+        ));
+    }
 }
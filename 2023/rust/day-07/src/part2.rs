@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use aoc_parse::final_parse_str;
 use itertools::Itertools;
 use nom::{
     bytes::complete::is_a,
@@ -168,16 +169,17 @@ impl Ord for Hand {
     }
 }
 
-#[tracing::instrument]
+#[tracing::instrument(skip_all)]
 pub fn process(_input: &str) -> miette::Result<String, AocError> {
-    let (_, hands) = parse_input(_input).unwrap();
+    let hands = final_parse_str(parse_input, _input)
+        .map_err(|error| AocError::parse_error(_input, error))?;
 
     let winnings: u64 = hands
         .iter()
         .sorted()
         .enumerate()
         .map(|(i, hand)| {
-            println!("Rank: {:}, Hand: {:?}", i + 1, hand.cards);
+            tracing::trace!(rank = i + 1, cards = ?hand.cards);
             hand.bid * (i as u64 + 1)
         })
         .sum();
@@ -196,26 +198,19 @@ fn parse_hand(input: &str) -> IResult<&str, Hand> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use std::path::PathBuf;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
-        assert_eq!("5905", process(input)?);
-        Ok(())
-    }
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
+    use super::*;
 
-    #[test]
-    fn test_comparison() -> miette::Result<()> {
-        let input = "JJJJJ 1
-AAAJJ 2
-AT3K7 3
-K2645 4";
-        assert_eq!("21", process(input)?);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part2.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process(&fixture.input)?);
         Ok(())
     }
 }
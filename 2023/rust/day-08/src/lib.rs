@@ -1,4 +1,31 @@
+use aoc_solution::Solution;
+
+use crate::custom_error::AocError;
+use crate::parser::Map;
+
 pub mod custom_error;
+pub mod parser;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Map;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Map, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(map: &Map) -> Result<u64, AocError> {
+        part1::process(map)
+    }
+
+    fn part2(map: &Map) -> Result<u64, AocError> {
+        part2::process(map)
+    }
+}
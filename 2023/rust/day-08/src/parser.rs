@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+
+use aoc_parse::final_parse_str;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, line_ending, one_of},
+    multi::{many1, separated_list1},
+    IResult,
+};
+
+use crate::custom_error::AocError;
+
+pub type Network = BTreeMap<String, (String, String)>;
+
+#[derive(Debug)]
+pub struct Map {
+    pub directions: Vec<char>,
+    pub network: Network,
+}
+
+#[tracing::instrument(skip_all)]
+pub fn parse(input: &str) -> miette::Result<Map, AocError> {
+    let (directions, network) =
+        final_parse_str(parse_input, input).map_err(|error| AocError::parse_error(input, error))?;
+
+    Ok(Map {
+        directions,
+        network,
+    })
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Vec<char>, Network)> {
+    let (input, directions) = many1(one_of("LR"))(input)?;
+    let (input, _) = many1(line_ending)(input)?;
+    let (input, nodes) = separated_list1(line_ending, node_parser)(input)?;
+
+    Ok((input, (directions, nodes.into_iter().collect())))
+}
+
+fn node_parser(input: &str) -> IResult<&str, (String, (String, String))> {
+    let (input, node_name) = many1(alphanumeric1)(input)?;
+    let (input, _) = tag(" = ")(input)?;
+    let (input, (left, right)) = nom::sequence::delimited(
+        tag("("),
+        nom::sequence::separated_pair(many1(alphanumeric1), tag(", "), many1(alphanumeric1)),
+        tag(")"),
+    )(input)?;
+
+    Ok((input, (node_name.join(""), (left.join(""), right.join("")))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert!(matches!(
+            parse("LR\n\nAAA = (BBB, CCC)\nBBB = (AAA\nCCC = (AAA, BBB)\n"),
+            Err(AocError::ParseError { span, .. }) if span.offset() == 21
+        ));
+    }
+}
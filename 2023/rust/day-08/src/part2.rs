@@ -1,22 +1,12 @@
-use std::collections::BTreeMap;
-
 use itertools::{FoldWhile, Itertools};
-use nom::{
-    bytes::complete::tag,
-    character::complete::{alphanumeric1, line_ending, one_of},
-    multi::{many1, separated_list1},
-    IResult,
-};
 
 use crate::custom_error::AocError;
+use crate::parser::Map;
 
-type Network = BTreeMap<String, (String, String)>;
-
-#[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String, AocError> {
-    let (_, (directions, nodes)) = parse_input(_input).unwrap();
-
-    let mut directions = directions.into_iter().cycle();
+#[tracing::instrument(skip_all)]
+pub fn process(map: &Map) -> miette::Result<u64, AocError> {
+    let nodes = &map.network;
+    let mut directions = map.directions.iter().cycle();
 
     let current_nodes: Vec<String> = nodes
         .keys()
@@ -32,7 +22,7 @@ pub fn process(_input: &str) -> miette::Result<String, AocError> {
                     .iter()
                     .map(|node| {
                         let (left, right) = nodes.get(node).unwrap();
-                        if directions == 'L' {
+                        if *directions == 'L' {
                             left.to_string()
                         } else {
                             right.to_string()
@@ -42,7 +32,7 @@ pub fn process(_input: &str) -> miette::Result<String, AocError> {
 
                 steps += 1;
 
-                println!("{:?}: {:?}", steps, current_nodes);
+                tracing::trace!(steps, ?current_nodes);
 
                 if current_nodes.iter().all(|node| node.ends_with('Z')) {
                     FoldWhile::Done((steps, current_nodes))
@@ -53,46 +43,28 @@ pub fn process(_input: &str) -> miette::Result<String, AocError> {
         )
         .into_inner();
 
-    Ok((steps).to_string())
-}
-
-fn parse_input(input: &str) -> IResult<&str, (Vec<char>, Network)> {
-    let (input, directions) = many1(one_of("LR"))(input)?;
-    let (input, _) = many1(line_ending)(input)?;
-    let (input, nodes) = separated_list1(line_ending, node_parser)(input)?;
-
-    Ok((input, (directions, nodes.into_iter().collect())))
-}
-
-fn node_parser(input: &str) -> IResult<&str, (String, (String, String))> {
-    let (input, node_name) = many1(alphanumeric1)(input)?;
-    let (input, _) = tag(" = ")(input)?;
-    let (input, (left, right)) = nom::sequence::delimited(
-        tag("("),
-        nom::sequence::separated_pair(many1(alphanumeric1), tag(", "), many1(alphanumeric1)),
-        tag(")"),
-    )(input)?;
-
-    Ok((input, (node_name.join(""), (left.join(""), right.join("")))))
+    Ok(steps)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use std::path::PathBuf;
 
-    #[test]
-    fn test_process1() -> miette::Result<()> {
-        let input = "LR
+    use aoc_input::Fixture;
+    use rstest::rstest;
 
-11A = (11B, XXX)
-11B = (XXX, 11Z)
-11Z = (11B, XXX)
-22A = (22B, XXX)
-22B = (22C, 22C)
-22C = (22Z, 22Z)
-22Z = (22B, 22B)
-XXX = (XXX, XXX)";
-        assert_eq!("6", process(input)?);
+    use super::*;
+    use crate::parser::parse;
+
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part2.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(
+            fixture.expected,
+            process(&parse(&fixture.input)?)?.to_string()
+        );
         Ok(())
     }
 }
@@ -1,4 +1,31 @@
+use aoc_solution::Solution;
+
+use crate::custom_error::AocError;
+use crate::parser::History;
+
 pub mod custom_error;
+pub mod parser;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<History>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Vec<History>, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(histories: &Vec<History>) -> Result<i32, AocError> {
+        part1::process(histories)
+    }
+
+    fn part2(histories: &Vec<History>) -> Result<i32, AocError> {
+        part2::process(histories)
+    }
+}
@@ -0,0 +1,39 @@
+use aoc_parse::final_parse_str;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    multi::separated_list1,
+    IResult,
+};
+
+use crate::custom_error::AocError;
+
+pub type History = Vec<i32>;
+
+#[tracing::instrument(skip_all)]
+pub fn parse(input: &str) -> miette::Result<Vec<History>, AocError> {
+    final_parse_str(parse_input, input).map_err(|error| AocError::parse_error(input, error))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<History>> {
+    let (input, histories) = separated_list1(line_ending, parse_history)(input)?;
+    Ok((input, histories))
+}
+
+fn parse_history(input: &str) -> IResult<&str, History> {
+    let (input, history) = separated_list1(tag(" "), complete::i32)(input)?;
+    Ok((input, history))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert!(matches!(
+            parse("0 3 6\n1 x 3\n4 5 6\n"),
+            Err(AocError::ParseError { span, .. }) if span.offset() == 8
+        ));
+    }
+}
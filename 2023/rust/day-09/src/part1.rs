@@ -1,30 +1,13 @@
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, line_ending},
-    multi::separated_list1,
-    IResult,
-};
-
 use crate::custom_error::AocError;
+use crate::parser::History;
 
-#[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String, AocError> {
-    let (_, histories) = parse_input(_input).unwrap();
+#[tracing::instrument(skip_all)]
+pub fn process(histories: &[History]) -> miette::Result<i32, AocError> {
     let result = histories.iter().fold(0, |extrapolation_sum, history| {
         extrapolation_sum + extrapolate(history)
     });
 
-    Ok(result.to_string())
-}
-
-fn parse_input(input: &str) -> IResult<&str, Vec<Vec<i32>>> {
-    let (input, histories) = separated_list1(line_ending, parse_history)(input)?;
-    Ok((input, histories))
-}
-
-fn parse_history(input: &str) -> IResult<&str, Vec<i32>> {
-    let (input, history) = separated_list1(tag(" "), complete::i32)(input)?;
-    Ok((input, history))
+    Ok(result)
 }
 
 fn extrapolate(history: &[i32]) -> i32 {
@@ -60,14 +43,23 @@ fn get_differences(history: &[i32]) -> Vec<i32> {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
     use super::*;
+    use crate::parser::parse;
 
-    #[test]
-    fn test_process1() -> miette::Result<()> {
-        let input = "0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45";
-        assert_eq!("114", process(input)?);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part1.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(
+            fixture.expected,
+            process(&parse(&fixture.input)?)?.to_string()
+        );
         Ok(())
     }
 }
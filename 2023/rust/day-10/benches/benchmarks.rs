@@ -1,4 +1,4 @@
-use day_10::*;
+use y2023_day_10::*;
 
 fn main() {
     // Run registered benchmarks.
@@ -6,17 +6,17 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = aoc_input::Source::from_env()
+        .read(env!("CARGO_MANIFEST_DIR"), 1)
+        .unwrap();
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
-}
\ No newline at end of file
+fn part2(bencher: divan::Bencher) {
+    let input = aoc_input::Source::from_env()
+        .read(env!("CARGO_MANIFEST_DIR"), 2)
+        .unwrap();
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
+}
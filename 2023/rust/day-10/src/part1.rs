@@ -12,7 +12,8 @@ struct Pipe {
     holes: (Coordinate, Coordinate),
 }
 
-#[tracing::instrument]
+#[allow(clippy::needless_borrow)]
+#[tracing::instrument(skip_all)]
 pub fn process(_input: &str) -> miette::Result<String, AocError> {
     let (start_coordinates, grid) = parse_input(_input);
 
@@ -29,6 +30,7 @@ pub fn process(_input: &str) -> miette::Result<String, AocError> {
     Ok(max_loop_length.to_string())
 }
 
+#[allow(clippy::collapsible_match)]
 fn parse_input(input: &str) -> (Coordinate, Grid) {
     let mut grid = Grid::new();
     let mut start_coordinates = (0, 0);
@@ -148,27 +150,19 @@ fn follow_pipe(start_coordinates: &Coordinate, pipe: &Pipe, grid: &Grid) -> u32
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use std::path::PathBuf;
 
-    #[test]
-    fn test_process1() -> miette::Result<()> {
-        let input = "-L|F7
-7S-7|
-L|7||
--L-J|
-L|-JF";
-        assert_eq!("4", process(input)?);
-        Ok(())
-    }
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
+    use super::*;
 
-    #[test]
-    fn test_process2() -> miette::Result<()> {
-        let input = "7-F7-
-.FJ|7
-SJLL7
-|F--J
-LJ.LJ";
-        assert_eq!("8", process(input)?);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part1.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process(&fixture.input)?);
         Ok(())
     }
 }
@@ -17,7 +17,8 @@ struct Pipe {
     holes: (Coordinate, Coordinate),
 }
 
-#[tracing::instrument]
+#[allow(clippy::needless_borrow)]
+#[tracing::instrument(skip_all)]
 pub fn process(_input: &str) -> miette::Result<String, AocError> {
     let (start_coordinates, grid, grid_width, grid_height) = parse_input(_input);
 
@@ -36,6 +37,7 @@ pub fn process(_input: &str) -> miette::Result<String, AocError> {
     Ok(max_area.to_string())
 }
 
+#[allow(clippy::collapsible_match)]
 fn parse_input(input: &str) -> (Coordinate, Grid, usize, usize) {
     let mut grid = Grid::new();
     let mut start_coordinates = (0, 0);
@@ -170,6 +172,7 @@ fn follow_pipe<'a>(
     pipe_coordinates
 }
 
+#[allow(clippy::needless_borrow)]
 fn get_in_loop_area(
     loop_coordinates: &PipeCoordinates,
     grid_height: usize,
@@ -209,36 +212,19 @@ fn calculate_ray_intersections(loop_coordinates: &PipeCoordinates, x: usize, y:
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use std::path::PathBuf;
 
-    #[test]
-    fn test_process1() -> miette::Result<()> {
-        let input = "...........
-.S-------7.
-.|F-----7|.
-.||.....||.
-.||.....||.
-.|L-7.F-J|.
-.|..|.|..|.
-.L--J.L--J.
-...........";
-        assert_eq!("4", process(input)?);
-        Ok(())
-    }
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
+    use super::*;
 
-    #[test]
-    fn test_process2() -> miette::Result<()> {
-        let input = ".F----7F7F7F7F-7....
-.|F--7||||||||FJ....
-.||.FJ||||||||L7....
-FJL7L7LJLJ||LJ.L-7..
-L--J.L7...LJS7F-7L7.
-....F-J..F7FJ|L7L7L7
-....L7.F7||L7|.L7L7|
-.....|FJLJ|FJ|F7|.LJ
-....FJL-7.||.||||...
-....L---J.LJ.LJLJ...";
-        assert_eq!("8", process(input)?);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part2.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process(&fixture.input)?);
         Ok(())
     }
 }
@@ -4,7 +4,7 @@ use itertools::Itertools;
 
 use crate::custom_error::AocError;
 
-#[tracing::instrument]
+#[tracing::instrument(skip_all)]
 pub fn process(_input: &str) -> miette::Result<String, AocError> {
     let expended_universe = expend_universe(_input);
 
@@ -27,7 +27,7 @@ pub fn process(_input: &str) -> miette::Result<String, AocError> {
 
             let distance = manhattan_distance(&a, &b);
 
-            println!("{:?} -> {:?} = {}", a, b, distance);
+            tracing::trace!(?a, ?b, distance);
 
             distance
         })
@@ -48,10 +48,8 @@ fn expand_rows(input: &str) -> String {
     for (i, line) in input.lines().enumerate() {
         universe.push(line.to_string());
         if line.chars().all(|c| c == '.') {
-            println!("expended row: {}", i + 1);
-            for _ in 0..9 {
-                universe.push(line.to_string());
-            }
+            tracing::debug!(row = i + 1, "expanded");
+            universe.push(line.to_string());
         }
     }
     universe.join("\n")
@@ -71,10 +69,8 @@ fn expand_columns(input: &str) -> String {
     for (i, column) in columns {
         universe_columns.push(column.to_string());
         if column.chars().all(|c| c == '.') {
-            println!("expended column: {}", i + 1);
-            for _ in 0..9 {
-                universe_columns.push(column.to_string());
-            }
+            tracing::debug!(column = i + 1, "expanded");
+            universe_columns.push(column.to_string());
         }
     }
 
@@ -113,6 +109,11 @@ fn manhattan_distance(a: &(usize, usize), b: &(usize, usize)) -> usize {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -157,20 +158,12 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
-
-        assert_eq!("37", process(input)?);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part1.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process(&fixture.input)?);
         Ok(())
     }
 }
@@ -4,11 +4,19 @@ use itertools::Itertools;
 
 use crate::custom_error::AocError;
 
-#[tracing::instrument]
+/// How many times wider an empty row or column is in part 2.
+const EXPANSION: usize = 1_000_000;
+
+#[tracing::instrument(skip_all)]
 pub fn process(_input: &str) -> miette::Result<String, AocError> {
-    let (expended_rows, expended_columns) = expend_universe(_input);
+    process_with_expansion(_input, EXPANSION)
+}
 
-    let grid = parse_input(_input);
+/// Distances with every empty row and column `expansion` times wider.
+pub fn process_with_expansion(input: &str, expansion: usize) -> miette::Result<String, AocError> {
+    let (expended_rows, expended_columns) = expend_universe(input);
+
+    let grid = parse_input(input);
 
     let galaxies: Vec<(usize, usize)> = grid
         .iter()
@@ -20,7 +28,7 @@ pub fn process(_input: &str) -> miette::Result<String, AocError> {
 
     let galaxy_pairs = galaxies.iter().combinations(2);
 
-    let expension_multiplier = 1000000 - 1;
+    let expension_multiplier = expansion - 1;
 
     let galaxy_distances = galaxy_pairs.map(|pair| {
         let mut a = *pair[0];
@@ -34,10 +42,7 @@ pub fn process(_input: &str) -> miette::Result<String, AocError> {
 
         let distance = manhattan_distance(&a, &b);
 
-        // println!(
-        //     "{:?}:{:?} -> {:?}:{:?} = {}",
-        //     pair[0], pair[1], a, b, distance
-        // );
+        tracing::trace!(from = ?pair[0], to = ?pair[1], ?a, ?b, distance);
 
         distance
     });
@@ -56,7 +61,7 @@ fn expand_rows(input: &str) -> HashSet<usize> {
 
     for (i, line) in input.lines().enumerate() {
         if line.chars().all(|c| c == '.') {
-            println!("expended row: {}", i);
+            tracing::debug!(row = i, "expanded");
             expended_rows.insert(i);
         }
     }
@@ -76,7 +81,7 @@ fn expand_columns(input: &str) -> HashSet<usize> {
 
     for (i, column) in columns {
         if column.chars().all(|c| c == '.') {
-            println!("expended column: {}", i);
+            tracing::debug!(column = i, "expanded");
             expended_columns.insert(i);
         }
     }
@@ -102,22 +107,32 @@ fn manhattan_distance(a: &(usize, usize), b: &(usize, usize)) -> usize {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
     use super::*;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
-
-        assert_eq!("1030", process(input)?);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part2.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process(&fixture.input)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(2, "374")]
+    #[case(10, "1030")]
+    #[case(100, "8410")]
+    fn test_process_with_expansion(
+        #[case] expansion: usize,
+        #[case] expected: &str,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(PathBuf::from("fixtures/example/part2.txt"))?;
+        assert_eq!(expected, process_with_expansion(&fixture.input, expansion)?);
         Ok(())
     }
 }
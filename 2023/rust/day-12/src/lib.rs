@@ -1,4 +1,31 @@
+use aoc_solution::Solution;
+
+use crate::custom_error::AocError;
+use crate::parser::Record;
+
 pub mod custom_error;
+pub mod parser;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Vec<Record>, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(records: &Vec<Record>) -> Result<u32, AocError> {
+        part1::process(records)
+    }
+
+    fn part2(records: &Vec<Record>) -> Result<u32, AocError> {
+        part2::process(records)
+    }
+}
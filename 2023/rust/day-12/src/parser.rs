@@ -0,0 +1,58 @@
+use nom::{
+    bytes::complete::{is_a, tag},
+    character::complete::{self, space1},
+    combinator::all_consuming,
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::custom_error::AocError;
+
+/// One line of the condition records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub springs: String,
+    pub groups: Vec<u32>,
+}
+
+#[tracing::instrument(skip_all)]
+pub fn parse(input: &str) -> miette::Result<Vec<Record>, AocError> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, (springs, groups)) = all_consuming(parse_line)(line)
+                .map_err(|error| AocError::parse_error(input, error))?;
+
+            Ok(Record {
+                springs: springs.to_string(),
+                groups,
+            })
+        })
+        .collect()
+}
+
+fn parse_line(input: &str) -> IResult<&str, (&str, Vec<u32>)> {
+    let (input, (springs, groups)) = separated_pair(
+        is_a(".?#"),
+        space1,
+        separated_list1(tag(","), complete::u32),
+    )(input)?;
+
+    Ok((input, (springs, groups)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "???.### 1,1,3
+.??..??...?##. 1,x,3";
+        let Err(AocError::ParseError { span, .. }) = parse(input) else {
+            panic!("a malformed record should not parse");
+        };
+        assert_eq!(input.find('x').unwrap() - 1, span.offset());
+    }
+}
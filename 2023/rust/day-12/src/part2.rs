@@ -1,44 +1,26 @@
 use cached::proc_macro::cached;
 use itertools::{Itertools, Position};
-use nom::{
-    bytes::complete::{is_a, tag},
-    character::complete::{self, space1},
-    multi::separated_list1,
-    sequence::separated_pair,
-    IResult,
-};
 
 use crate::custom_error::AocError;
-
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let result = input
-        .lines()
-        .map(count_damaged_springs_arrangements)
-        .sum::<u32>();
-
-    Ok(result.to_string())
-}
-
-fn count_damaged_springs_arrangements(line: &str) -> u32 {
-    let (_, (springs, groups)) = parse_line(line).unwrap();
-
-    let extended_springs = String::from(".") + &springs + ".";
-
-    count_arrangements(extended_springs, groups)
-}
-
-fn parse_line(input: &str) -> IResult<&str, (String, Vec<u32>)> {
-    let (input, (springs, groups)) = separated_pair(
-        is_a(".?#"),
-        space1,
-        separated_list1(tag(","), complete::u32),
-    )(input)?;
-
-    let springs = std::iter::repeat(springs).take(5).join("?");
-    let groups = std::iter::repeat(groups).take(5).flatten().collect();
-
-    Ok((input, (springs, groups)))
+use crate::parser::Record;
+
+#[tracing::instrument(skip_all)]
+pub fn process(records: &[Record]) -> miette::Result<u32, AocError> {
+    let result = records
+        .iter()
+        .map(|record| {
+            let springs = std::iter::repeat_n(&record.springs, 5).join("?");
+            let groups = std::iter::repeat_n(&record.groups, 5)
+                .flatten()
+                .copied()
+                .collect();
+
+            let extended_springs = String::from(".") + &springs + ".";
+            count_arrangements(extended_springs, groups)
+        })
+        .sum();
+
+    Ok(result)
 }
 
 #[cached]
@@ -85,17 +67,23 @@ fn group_fits(window: &[char]) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use std::path::PathBuf;
+
+    use aoc_input::Fixture;
+    use rstest::rstest;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1";
-        assert_eq!("525152", process(input)?);
+    use super::*;
+    use crate::parser::parse;
+
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part2.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(
+            fixture.expected,
+            process(&parse(&fixture.input)?)?.to_string()
+        );
         Ok(())
     }
 }
@@ -2,14 +2,14 @@ use std::vec;
 
 use crate::custom_error::AocError;
 
-#[tracing::instrument]
+#[tracing::instrument(skip_all)]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
     let blocks = input.split("\n\n").collect::<Vec<&str>>();
 
     let (reflected_rows, reflected_columns) = blocks.iter().enumerate().fold(
         (vec![], vec![]),
         |(mut reflected_rows, mut reflected_columns), (i, block)| {
-            println!("\nanalyzing block #{}", i + 1);
+            tracing::debug!(block = i + 1, "analyzing");
             reflected_rows.push(get_horizontal_reflection(block));
             reflected_columns.push(get_vertical_reflection(block));
 
@@ -27,7 +27,7 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
 }
 
 fn get_horizontal_reflection(block: &str) -> u32 {
-    println!("Looking for horizontal reflection");
+    tracing::trace!("looking for horizontal reflection");
     let lines = block.lines().collect::<Vec<_>>();
 
     let possible_reflections_id =
@@ -37,19 +37,17 @@ fn get_horizontal_reflection(block: &str) -> u32 {
             .filter_map(|(i, rows)| if rows[0] == rows[1] { Some(i) } else { None });
 
     for id in possible_reflections_id {
-        println!("id: {}", id + 1);
+        tracing::trace!(row = id + 1, "candidate");
         let below_rows = lines[id + 2..].to_vec();
         let above_rows = lines[..id].to_vec();
 
         if below_rows
             .iter()
             .zip(above_rows.iter().rev())
-            .inspect(|(a, b)| {
-                println!("a: {}, b: {}", a, b);
-            })
+            .inspect(|(a, b)| tracing::trace!(a, b))
             .all(|(a, b)| a == b)
         {
-            println!("Found reflection at {}", id + 1);
+            tracing::debug!(row = id + 1, "found reflection");
             return id as u32 + 1;
         }
     }
@@ -58,7 +56,7 @@ fn get_horizontal_reflection(block: &str) -> u32 {
 }
 
 fn get_vertical_reflection(block: &str) -> u32 {
-    println!("Looking for vertical reflection");
+    tracing::trace!("looking for vertical reflection");
     let transposed_block = transpose(block);
 
     get_horizontal_reflection(&transposed_block.join("\n"))
@@ -82,26 +80,19 @@ fn transpose(block: &str) -> Vec<String> {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
     use super::*;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#";
-        assert_eq!("405", process(input)?);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part1.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process(&fixture.input)?);
         Ok(())
     }
 }
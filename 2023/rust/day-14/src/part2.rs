@@ -13,7 +13,10 @@ enum Direction {
     West,
 }
 
-#[tracing::instrument]
+/// The rocks as `tilt_grid` takes and returns them.
+type Rocks = Vec<((u32, u32), char)>;
+
+#[tracing::instrument(skip_all)]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
     let grid = parse_input(input);
 
@@ -42,31 +45,39 @@ fn parse_input(input: &str) -> HashMap<(u32, u32), char> {
     grid
 }
 
+/// Spins the grid `cycles` times. The rocks settle into a loop after a few
+/// cycles, so the grids seen so far are kept to skip the repeats.
 fn grid_cycle(grid: HashMap<(u32, u32), char>, cycles: u32) -> HashMap<(u32, u32), char> {
-    let mut current_grid = grid.into_iter().collect();
-
-    for i in 0..cycles {
-        if i % 1000 == 0 {
-            println!("cycle {}", i);
+    let mut current_grid: Rocks = grid.into_iter().sorted().collect();
+    let mut seen: HashMap<Rocks, u32> = HashMap::new();
+
+    let mut i = 0;
+    while i < cycles {
+        if let Some(start) = seen.insert(current_grid.clone(), i) {
+            let period = i - start;
+            tracing::debug!(cycle = i, start, period, "loop found");
+            for _ in 0..(cycles - i) % period {
+                current_grid = spin_grid(current_grid);
+            }
+            break;
         }
-        // print_grid(&current_grid);
-        // println!("tilting north");
-        current_grid = tilt_grid(current_grid, Direction::North);
-        // print_grid(&current_grid);
-        // println!("tilting west");
-        current_grid = tilt_grid(current_grid, Direction::West);
-        // print_grid(&current_grid);
-        // println!("tilting south");
-        current_grid = tilt_grid(current_grid, Direction::South);
-        // print_grid(&current_grid);
-        // println!("tilting east");
-        current_grid = tilt_grid(current_grid, Direction::East);
-        // println!("grid after {} cycles:", i + 1);
-        // print_grid(&current_grid);
+        current_grid = spin_grid(current_grid);
+        i += 1;
+        tracing::trace!(cycle = i, grid = %render_grid(&current_grid));
     }
     current_grid.into_iter().collect()
 }
 
+fn spin_grid(grid: Rocks) -> Rocks {
+    let grid = tilt_grid(grid, Direction::North);
+    let grid = tilt_grid(grid, Direction::West);
+    let grid = tilt_grid(grid, Direction::South);
+    tilt_grid(grid, Direction::East)
+        .into_iter()
+        .sorted()
+        .collect()
+}
+
 #[cached]
 fn tilt_grid(grid: Vec<((u32, u32), char)>, direction: Direction) -> Vec<((u32, u32), char)> {
     let mut new_grid = Vec::new();
@@ -75,13 +86,12 @@ fn tilt_grid(grid: Vec<((u32, u32), char)>, direction: Direction) -> Vec<((u32,
         // for each column, make the rounded rocks roll to the north and store their new position in the displacement_mapping
         let new_stack = collapse_stack(stack.clone());
 
-        // println!(
-        //     "stack {} direction {:?}: {:?} -> {:?}",
-        //     i + 1,
-        //     direction,
-        //     stack.iter().map(|(_, c)| *c).join(""),
-        //     new_stack.iter().map(|(_, c)| *c).join("")
-        // );
+        tracing::trace!(
+            stack = i + 1,
+            ?direction,
+            before = %stack.iter().map(|(_, c)| *c).join(""),
+            after = %new_stack.iter().map(|(_, c)| *c).join(""),
+        );
 
         for (j, c) in new_stack {
             let coordinates = match direction {
@@ -173,35 +183,36 @@ fn collapse_stack(stack: Vec<(u32, char)>) -> Vec<(u32, char)> {
     new_stack
 }
 
-fn print_grid(grid: &HashMap<(u32, u32), char>) {
+/// The grid as in the puzzle text, for trace events.
+fn render_grid(grid: &[((u32, u32), char)]) -> String {
+    let grid: HashMap<(u32, u32), char> = grid.iter().copied().collect();
     let grid_width = grid.keys().map(|(x, _)| x).max().unwrap() + 1;
     let grid_height = grid.keys().map(|(_, y)| y).max().unwrap() + 1;
 
-    for y in 0..grid_height {
-        for x in 0..grid_width {
-            print!("{}", grid.get(&(x, y)).unwrap_or(&'.'));
-        }
-        println!();
-    }
+    (0..grid_height)
+        .map(|y| {
+            (0..grid_width)
+                .map(|x| grid.get(&(x, y)).unwrap_or(&'.'))
+                .collect::<String>()
+        })
+        .join("\n")
 }
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
     use super::*;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-        assert_eq!("64", process(input)?);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part2.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process(&fixture.input)?);
         Ok(())
     }
 }
@@ -0,0 +1,179 @@
+use aoc_grid::{Coordinates, Direction, Grid};
+
+use crate::parser::Tile;
+
+impl Tile {
+    fn get_next_steps(&self, step: &Step, grid: &Grid<Tile>) -> Vec<Step> {
+        let coordinates = step.coordinates;
+        let incoming_direction = step.direction;
+
+        match (self, incoming_direction) {
+            (Self::Empty, direction) => {
+                if let Some(next_coordinates) = grid.next(&coordinates, direction) {
+                    vec![Step {
+                        coordinates: next_coordinates,
+                        direction,
+                    }]
+                } else {
+                    vec![]
+                }
+            }
+
+            (Self::SlashMirror, direction) => {
+                let next_direction = match direction {
+                    Direction::Up => Direction::Right,
+                    Direction::Right => Direction::Up,
+                    Direction::Down => Direction::Left,
+                    Direction::Left => Direction::Down,
+                };
+
+                if let Some(next_coordinates) = grid.next(&coordinates, next_direction) {
+                    vec![Step {
+                        coordinates: next_coordinates,
+                        direction: next_direction,
+                    }]
+                } else {
+                    vec![]
+                }
+            }
+
+            (Self::BackslashMirror, direction) => {
+                let next_direction = match direction {
+                    Direction::Up => Direction::Left,
+                    Direction::Right => Direction::Down,
+                    Direction::Down => Direction::Right,
+                    Direction::Left => Direction::Up,
+                };
+
+                if let Some(next_coordinates) = grid.next(&coordinates, next_direction) {
+                    vec![Step {
+                        coordinates: next_coordinates,
+                        direction: next_direction,
+                    }]
+                } else {
+                    vec![]
+                }
+            }
+
+            (Self::VerticalSplitter, direction) => {
+                if direction == Direction::Up || direction == Direction::Down {
+                    if let Some(next_coordinates) = grid.next(&coordinates, direction) {
+                        vec![Step {
+                            coordinates: next_coordinates,
+                            direction,
+                        }]
+                    } else {
+                        vec![]
+                    }
+                } else {
+                    let mut possible_next_steps = vec![];
+
+                    if let Some(next_coordinates) = grid.next(&coordinates, Direction::Up) {
+                        possible_next_steps.push(Step {
+                            coordinates: next_coordinates,
+                            direction: Direction::Up,
+                        });
+                    }
+
+                    if let Some(next_coordinates) = grid.next(&coordinates, Direction::Down) {
+                        possible_next_steps.push(Step {
+                            coordinates: next_coordinates,
+                            direction: Direction::Down,
+                        });
+                    }
+
+                    possible_next_steps
+                }
+            }
+            (Self::HorizontalSplitter, direction) => {
+                if direction == Direction::Left || direction == Direction::Right {
+                    if let Some(next_coordinates) = grid.next(&coordinates, direction) {
+                        vec![Step {
+                            coordinates: next_coordinates,
+                            direction,
+                        }]
+                    } else {
+                        vec![]
+                    }
+                } else {
+                    let mut possible_next_steps = vec![];
+
+                    if let Some(next_coordinates) = grid.next(&coordinates, Direction::Left) {
+                        possible_next_steps.push(Step {
+                            coordinates: next_coordinates,
+                            direction: Direction::Left,
+                        });
+                    }
+
+                    if let Some(next_coordinates) = grid.next(&coordinates, Direction::Right) {
+                        possible_next_steps.push(Step {
+                            coordinates: next_coordinates,
+                            direction: Direction::Right,
+                        });
+                    }
+
+                    possible_next_steps
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Step {
+    pub coordinates: Coordinates,
+    pub direction: Direction,
+}
+
+#[derive(Debug)]
+pub struct LightBeam {
+    current_step: Step,
+    pub visited_steps: Vec<Step>,
+}
+
+impl LightBeam {
+    fn new(initial_step: Step) -> Self {
+        LightBeam {
+            current_step: initial_step.clone(),
+            visited_steps: vec![initial_step],
+        }
+    }
+
+    fn set_current_step(&mut self, step: Step) {
+        self.current_step = step.clone();
+        self.visited_steps.push(step);
+    }
+
+    fn raycast(&mut self, grid: &Grid<Tile>) {
+        let tile = grid[self.current_step.coordinates];
+        let next_possible_steps = tile.get_next_steps(&self.current_step, grid);
+
+        let next_possible_steps: Vec<_> = next_possible_steps
+            .into_iter()
+            .filter(|step| !self.already_visited(step))
+            .collect();
+
+        if next_possible_steps.is_empty() {
+            return;
+        }
+
+        for next_step in next_possible_steps {
+            self.set_current_step(next_step);
+            self.raycast(grid);
+        }
+    }
+
+    fn already_visited(&self, step: &Step) -> bool {
+        self.visited_steps.iter().any(|visited_step| {
+            visited_step.coordinates == step.coordinates && visited_step.direction == step.direction
+        })
+    }
+}
+
+pub fn traverse_grid(grid: &Grid<Tile>, initial_step: &Step) -> LightBeam {
+    let mut beam = LightBeam::new(initial_step.clone());
+
+    beam.raycast(grid);
+
+    beam
+}
@@ -1,4 +1,33 @@
+use aoc_grid::Grid;
+use aoc_solution::Solution;
+
+use crate::custom_error::AocError;
+use crate::parser::Tile;
+
+mod beam;
 pub mod custom_error;
+pub mod parser;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Grid<Tile>, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(grid: &Grid<Tile>) -> Result<usize, AocError> {
+        part1::process(grid)
+    }
+
+    fn part2(grid: &Grid<Tile>) -> Result<usize, AocError> {
+        part2::process(grid)
+    }
+}
@@ -0,0 +1,30 @@
+use aoc_grid::Grid;
+
+use crate::custom_error::AocError;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Tile {
+    Empty,
+    SlashMirror,
+    BackslashMirror,
+    VerticalSplitter,
+    HorizontalSplitter,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Empty),
+            '/' => Some(Self::SlashMirror),
+            '\\' => Some(Self::BackslashMirror),
+            '|' => Some(Self::VerticalSplitter),
+            '-' => Some(Self::HorizontalSplitter),
+            _ => None,
+        }
+    }
+}
+
+#[tracing::instrument(skip_all)]
+pub fn parse(input: &str) -> miette::Result<Grid<Tile>, AocError> {
+    Ok(Grid::parse(input, Tile::from_char)?)
+}
@@ -1,4 +1,31 @@
+use aoc_grid::Grid;
+use aoc_solution::Solution;
+
+use crate::custom_error::AocError;
+
 pub mod custom_error;
+pub mod parser;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Grid<u32>, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(grid: &Grid<u32>) -> Result<u32, AocError> {
+        part1::process(grid)
+    }
+
+    fn part2(grid: &Grid<u32>) -> Result<u32, AocError> {
+        part2::process(grid)
+    }
+}
@@ -0,0 +1,8 @@
+use aoc_grid::Grid;
+
+use crate::custom_error::AocError;
+
+#[tracing::instrument(skip_all)]
+pub fn parse(input: &str) -> miette::Result<Grid<u32>, AocError> {
+    Ok(Grid::parse(input, |c| c.to_digit(10))?)
+}
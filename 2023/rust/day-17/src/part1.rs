@@ -1,25 +1,8 @@
-use std::{
-    collections::{BinaryHeap, HashMap},
-    ops::Range,
-};
+use std::collections::{BinaryHeap, HashMap};
 
-use crate::custom_error::AocError;
+use aoc_grid::{Coordinates, Direction, Grid};
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-impl Direction {
-    fn orthogonal(&self) -> Vec<Self> {
-        match self {
-            Self::Up | Self::Down => vec![Self::Left, Self::Right],
-            Self::Left | Self::Right => vec![Self::Down, Self::Up],
-        }
-    }
-}
+use crate::custom_error::AocError;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 struct Step {
@@ -40,138 +23,49 @@ impl PartialOrd for Step {
     }
 }
 
-struct Grid {
-    tiles: Vec<Vec<u32>>,
-    width: Range<i32>,
-    height: Range<i32>,
-}
-
-impl Grid {
-    fn new(tiles: Vec<Vec<u32>>) -> Self {
-        let height = tiles.len() as i32;
-        let width = tiles.first().unwrap().len() as i32;
-
-        Self {
-            tiles,
-            width: (0..width),
-            height: (0..height),
-        }
-    }
-
-    fn get(&self, coordinates: &Coordinates) -> u32 {
-        self.tiles[coordinates.y as usize][coordinates.x as usize]
-    }
-
-    fn get_possible_steps(&self, current_step: &Step) -> Vec<Step> {
-        let mut possible_steps = vec![];
-
-        for direction in current_step.direction.orthogonal() {
-            let mut coordinates = current_step.coordinates;
-            let mut heat_loss = current_step.heat_loss;
-
-            for _ in 0..3 {
-                if let Some(next_coordinates) =
-                    coordinates.ranged_next(&direction, &self.width, &self.height)
-                {
-                    heat_loss += self.get(&next_coordinates);
-                    coordinates = next_coordinates;
-                    possible_steps.push(Step {
-                        coordinates,
-                        direction,
-                        heat_loss,
-                    });
-                } else {
-                    break;
-                }
+fn get_possible_steps(grid: &Grid<u32>, current_step: &Step) -> Vec<Step> {
+    let mut possible_steps = vec![];
+
+    for direction in current_step.direction.orthogonal() {
+        let mut coordinates = current_step.coordinates;
+        let mut heat_loss = current_step.heat_loss;
+
+        for _ in 0..3 {
+            if let Some(next_coordinates) = grid.next(&coordinates, direction) {
+                heat_loss += grid[next_coordinates];
+                coordinates = next_coordinates;
+                possible_steps.push(Step {
+                    coordinates,
+                    direction,
+                    heat_loss,
+                });
+            } else {
+                break;
             }
         }
-
-        possible_steps
-    }
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-struct Coordinates {
-    x: i32,
-    y: i32,
-}
-
-impl Coordinates {
-    fn next(&self, direction: &Direction) -> Coordinates {
-        match direction {
-            Direction::Up => Coordinates {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Direction::Down => Coordinates {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Direction::Left => Coordinates {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Direction::Right => Coordinates {
-                x: self.x + 1,
-                y: self.y,
-            },
-        }
     }
 
-    fn ranged_next(
-        &self,
-        direction: &Direction,
-        width_range: &Range<i32>,
-        height_range: &Range<i32>,
-    ) -> Option<Coordinates> {
-        let next = self.next(direction);
-
-        if width_range.contains(&next.x) && height_range.contains(&next.y) {
-            Some(next)
-        } else {
-            None
-        }
-    }
+    possible_steps
 }
 
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String, AocError> {
-    // get the grid
-    let grid: Grid = parse_input(input);
-
+#[tracing::instrument(skip_all)]
+pub fn process(grid: &Grid<u32>) -> miette::Result<u32, AocError> {
     // get starting point
-    let start = Coordinates {
-        x: grid.width.start,
-        y: grid.height.start,
-    };
+    let start = Coordinates { x: 0, y: 0 };
 
     // get end point
     let end = Coordinates {
-        x: grid.width.end - 1,
-        y: grid.height.end - 1,
+        x: grid.width() as i32 - 1,
+        y: grid.height() as i32 - 1,
     };
 
     // implement the algorithm to find the path with the least amount of heat loss
-    let minimal_heat_loss = find_minimal_heat_loss(&grid, &start, &end);
+    let minimal_heat_loss = find_minimal_heat_loss(grid, &start, &end);
 
-    Ok(minimal_heat_loss.to_string())
+    Ok(minimal_heat_loss)
 }
 
-fn parse_input(input: &str) -> Grid {
-    let grid = input
-        .trim()
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).expect("Invalid input"))
-                .collect::<Vec<u32>>()
-        })
-        .collect();
-
-    Grid::new(grid)
-}
-
-fn find_minimal_heat_loss(grid: &Grid, start: &Coordinates, end: &Coordinates) -> u32 {
+fn find_minimal_heat_loss(grid: &Grid<u32>, start: &Coordinates, end: &Coordinates) -> u32 {
     let mut minimal_heat_loss_per_coordinate: HashMap<(Coordinates, Direction), u32> =
         HashMap::new();
 
@@ -191,7 +85,7 @@ fn find_minimal_heat_loss(grid: &Grid, start: &Coordinates, end: &Coordinates) -
     });
 
     while let Some(step) = steps_to_check.pop() {
-        println!("{:?}", step);
+        tracing::trace!(?step);
 
         if step.coordinates == *end {
             return step.heat_loss;
@@ -203,15 +97,12 @@ fn find_minimal_heat_loss(grid: &Grid, start: &Coordinates, end: &Coordinates) -
                 .unwrap_or(&u32::MAX)
         {
             // another path already reached those coordinates while losing less heat, nothing else to do.
-            println!(
-                "Another path reached {:?} losing less heat",
-                step.coordinates
-            );
+            tracing::trace!(coordinates = ?step.coordinates, "another path lost less heat");
             continue;
         }
 
-        for next_step in grid.get_possible_steps(&step).iter() {
-            println!("possible next step: {:?}", next_step);
+        for next_step in get_possible_steps(grid, &step).iter() {
+            tracing::trace!(?next_step, "possible");
 
             if next_step.heat_loss
                 < *minimal_heat_loss_per_coordinate
@@ -234,24 +125,23 @@ fn find_minimal_heat_loss(grid: &Grid, start: &Coordinates, end: &Coordinates) -
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use std::path::PathBuf;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "2413432311323
-3215453535623
-3255245654254
-3446585845452
-4546657867536
-1438598798454
-4457876987766
-3637877979653
-4654967986887
-4564679986453
-1224686865563
-2546548887735
-4322674655533";
-        assert_eq!("102", process(input)?);
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::parser::parse;
+
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part1.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(
+            fixture.expected,
+            process(&parse(&fixture.input)?)?.to_string()
+        );
         Ok(())
     }
 }
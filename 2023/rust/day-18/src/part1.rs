@@ -1,4 +1,4 @@
-use std::collections::{BinaryHeap, HashSet, VecDeque};
+use std::collections::{BinaryHeap, HashSet};
 use std::ops::Range;
 
 use nom::{
@@ -18,6 +18,7 @@ use crate::custom_error::AocError;
 struct Instruction {
     direction: char,
     distance: u32,
+    #[allow(dead_code)]
     edge_color: String,
 }
 
@@ -39,12 +40,14 @@ impl PartialOrd for Coordinate {
     }
 }
 
+#[allow(dead_code)]
 #[derive(Debug)]
 struct Step {
     coordinates: Coordinate,
     direction: Direction,
 }
 
+#[allow(dead_code)]
 #[derive(Debug)]
 enum Direction {
     Up,
@@ -61,9 +64,10 @@ enum Direction {
     RightToDown,
 }
 
-#[tracing::instrument]
+#[tracing::instrument(skip_all)]
 pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let (_, mut instruction_list) = parse_input(input).unwrap();
+    let (_, mut instruction_list) =
+        parse_input(input).map_err(|error| AocError::parse_error(input, error))?;
 
     let mut digged_tiles = Vec::new();
 
@@ -121,24 +125,24 @@ fn get_area(polygone: Vec<Coordinate>) -> u32 {
 
     let mut area = (min_x..max_x + 1).len() as u32 * (min_y..max_y + 1).len() as u32;
 
-    println!("Starting Area: {}", area);
+    tracing::debug!(area, "starting");
 
     coordinates_to_visit.push(Coordinate { x: min_x, y: min_y });
 
     while !coordinates_to_visit.is_empty() {
         let current_coordinate = coordinates_to_visit.pop().unwrap();
 
-        print!("({}, {}) -> ", current_coordinate.x, current_coordinate.y);
+        let (x, y) = (current_coordinate.x, current_coordinate.y);
 
         if visited_coordinates.contains(&current_coordinate) {
-            println!("Already Visited!");
+            tracing::trace!(x, y, "already visited");
             continue;
         } else {
             visited_coordinates.insert(current_coordinate);
         }
 
         if polygone.contains(&current_coordinate) {
-            println!("In Polygone!");
+            tracing::trace!(x, y, "in polygon");
             continue;
         }
 
@@ -151,7 +155,7 @@ fn get_area(polygone: Vec<Coordinate>) -> u32 {
             .for_each(|c| coordinates_to_visit.push(*c));
 
         area -= 1;
-        println!("Is outside! Area: {}", area);
+        tracing::trace!(x, y, area, "outside");
     }
 
     area
@@ -221,25 +225,19 @@ fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
     use super::*;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "R 6 (#70c710)
-D 5 (#0dc571)
-L 2 (#5713f0)
-D 2 (#d2c081)
-R 2 (#59c680)
-D 2 (#411b91)
-L 5 (#8ceee2)
-U 2 (#caa173)
-L 1 (#1b58a2)
-U 2 (#caa171)
-R 2 (#7807d2)
-U 3 (#a77fa3)
-L 2 (#015232)
-U 2 (#7a21e3)";
-        assert_eq!("62", process(input)?);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part1.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process(&fixture.input)?);
         Ok(())
     }
 }
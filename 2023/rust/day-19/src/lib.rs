@@ -1,4 +1,31 @@
+use aoc_solution::Solution;
+
+use crate::custom_error::AocError;
+use crate::parser::System;
+
 pub mod custom_error;
+pub mod parser;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+    type Answer1 = u32;
+    type Answer2 = u64;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<System, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(system: &System) -> Result<u32, AocError> {
+        part1::process(system)
+    }
+
+    fn part2(system: &System) -> Result<u64, AocError> {
+        part2::process(system)
+    }
+}
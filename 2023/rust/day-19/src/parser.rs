@@ -0,0 +1,173 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use aoc_parse::{final_parse, IResult, Span};
+use nom::{
+    branch::alt,
+    character::complete::{self, line_ending, multispace1},
+    combinator::cut,
+    multi::separated_list1,
+    sequence::{delimited, separated_pair, terminated},
+    Parser,
+};
+use nom_supreme::{tag::complete::tag, ParserExt};
+
+use crate::custom_error::AocError;
+
+#[derive(Debug)]
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+pub type WorkflowMap = HashMap<String, Vec<Rule>>;
+
+#[derive(Debug)]
+pub struct Rule {
+    pub redirection: String,
+    pub condition: Option<(char, Ordering, u32)>,
+}
+
+#[derive(Debug)]
+pub struct Part {
+    pub ratings: HashMap<char, u32>,
+}
+
+#[derive(Debug)]
+pub struct System {
+    pub workflows: WorkflowMap,
+    pub parts: Vec<Part>,
+}
+
+#[tracing::instrument(skip_all)]
+pub fn parse(input: &str) -> miette::Result<System, AocError> {
+    final_parse(parse_input, input).map_err(|error| AocError::parse_error(input, error))
+}
+
+fn parse_input(input: Span) -> IResult<System> {
+    let (input, workflows) = workflows_parser(input)?;
+    let (input, _) = multispace1(input)?;
+
+    let (input, parts) = parts_parser(input)?;
+
+    let workflows = workflows
+        .into_iter()
+        .map(|workflow| (workflow.name, workflow.rules))
+        .collect();
+
+    Ok((input, System { workflows, parts }))
+}
+
+fn workflows_parser(input: Span) -> IResult<Vec<Workflow>> {
+    let (input, workflows) = separated_list1(line_ending, workflow_parser)(input)?;
+
+    Ok((input, workflows))
+}
+
+fn workflow_parser(input: Span) -> IResult<Workflow> {
+    let (input, name) = complete::alpha1(input)?;
+    let (input, rules) = delimited(
+        complete::char('{'),
+        cut(separated_list1(complete::char(','), rule_parser)),
+        cut(complete::char('}')),
+    )
+    .context("workflow")
+    .parse(input)?;
+
+    Ok((
+        input,
+        Workflow {
+            name: name.fragment().to_string(),
+            rules,
+        },
+    ))
+}
+
+fn rule_parser(input: Span) -> IResult<Rule> {
+    let (input, rule) = alt((conditionnal_rule_parser, unconditionnal_rule_parser))
+        .context("rule")
+        .parse(input)?;
+
+    Ok((input, rule))
+}
+
+fn conditionnal_rule_parser(input: Span) -> IResult<Rule> {
+    let (input, condition) = terminated(condition_parser, cut(complete::char(':')))(input)?;
+    let (input, redirection) = cut(complete::alpha1)(input)?;
+
+    Ok((
+        input,
+        Rule {
+            redirection: redirection.fragment().to_string(),
+            condition: Some(condition),
+        },
+    ))
+}
+
+fn unconditionnal_rule_parser(input: Span) -> IResult<Rule> {
+    let (input, redirection) = complete::alpha1(input)?;
+
+    Ok((
+        input,
+        Rule {
+            redirection: redirection.fragment().to_string(),
+            condition: None,
+        },
+    ))
+}
+
+fn condition_parser(input: Span) -> IResult<(char, Ordering, u32)> {
+    let (input, field) = complete::one_of("xmas")(input)?;
+    let (input, op) = complete::one_of("<>")(input)?;
+    let (input, value) = cut(complete::u32)(input)?;
+
+    let op = match op {
+        '<' => Ordering::Less,
+        '>' => Ordering::Greater,
+        _ => unreachable!(),
+    };
+
+    Ok((input, (field, op, value)))
+}
+
+fn parts_parser(input: Span) -> IResult<Vec<Part>> {
+    let (input, parts) = separated_list1(line_ending, part_parser)(input)?;
+    Ok((input, parts))
+}
+
+fn part_parser(input: Span) -> IResult<Part> {
+    let (input, ratings) = delimited(
+        tag("{"),
+        cut(separated_list1(
+            tag(","),
+            separated_pair(complete::one_of("xmas"), complete::char('='), complete::u32),
+        )),
+        cut(tag("}")),
+    )
+    .context("part")
+    .parse(input)?;
+
+    Ok((
+        input,
+        Part {
+            ratings: ratings.into_iter().collect(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "px{a<2006:qkq,m>20x0:A,rfg}
+in{s<1351:px,R}
+
+{x=787,m=2655,a=1222,s=2876}";
+        let Err(AocError::ParseError { span, message, .. }) = parse(input) else {
+            panic!("a malformed rule should not parse");
+        };
+        assert_eq!(input.find("x0").unwrap(), span.offset());
+        assert_eq!("expected ':' in rule in workflow", message);
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_solution::Solution;
+use y2024_day_01::Day01;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+fn read_input(part: u8) -> String {
+    aoc_input::Source::from_env()
+        .read(env!("CARGO_MANIFEST_DIR"), part)
+        .unwrap()
+}
+
+#[divan::bench]
+fn parse(bencher: divan::Bencher) {
+    let input = read_input(1);
+    bencher.bench(|| Day01::parse(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part1(bencher: divan::Bencher) {
+    let input = Day01::parse(&read_input(1)).unwrap();
+    bencher.bench(|| Day01::part1(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part2(bencher: divan::Bencher) {
+    let input = Day01::parse(&read_input(2)).unwrap();
+    bencher.bench(|| Day01::part2(divan::black_box(&input)).unwrap());
+}
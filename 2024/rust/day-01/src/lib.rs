@@ -0,0 +1,31 @@
+use aoc_solution::Solution;
+
+use crate::custom_error::AocError;
+use crate::parser::Lists;
+
+pub mod custom_error;
+pub mod parser;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Lists;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Lists, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(lists: &Lists) -> Result<u64, AocError> {
+        part1::process(lists)
+    }
+
+    fn part2(lists: &Lists) -> Result<u64, AocError> {
+        part2::process(lists)
+    }
+}
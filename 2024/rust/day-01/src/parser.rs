@@ -0,0 +1,56 @@
+use aoc_parse::final_parse_str;
+use nom::{
+    character::complete::{self, line_ending, space1},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::custom_error::AocError;
+
+/// The two columns of location IDs, one per historian group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lists {
+    pub left: Vec<u64>,
+    pub right: Vec<u64>,
+}
+
+#[tracing::instrument(skip_all)]
+pub fn parse(input: &str) -> miette::Result<Lists, AocError> {
+    let pairs =
+        final_parse_str(parse_input, input).map_err(|error| AocError::parse_error(input, error))?;
+    let (left, right) = pairs.into_iter().unzip();
+    Ok(Lists { left, right })
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<(u64, u64)>> {
+    separated_list1(
+        line_ending,
+        separated_pair(complete::u64, space1, complete::u64),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> miette::Result<(), AocError> {
+        assert_eq!(
+            Lists {
+                left: vec![3, 4],
+                right: vec![4, 9],
+            },
+            parse("3   4\n4   9\n")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(matches!(
+            parse("3   4\n4 x\n5   6\n"),
+            Err(AocError::ParseError { span, .. }) if span.offset() == 6
+        ));
+    }
+}
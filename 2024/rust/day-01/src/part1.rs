@@ -1,23 +1,39 @@
-fn main() {
-    let input = include_str!("../input.txt");
+use crate::custom_error::AocError;
+use crate::parser::Lists;
 
-    let (mut left, mut right) =
-        input
-            .lines()
-            .fold((Vec::new(), Vec::new()), |(mut left, mut right), line| {
-                let (l, r) = line.split_once("   ").unwrap();
-                left.push(l.parse::<isize>().unwrap());
-                right.push(r.parse::<isize>().unwrap());
-                (left, right)
-            });
+#[tracing::instrument(skip_all)]
+pub fn process(lists: &Lists) -> miette::Result<u64, AocError> {
+    let mut left = lists.left.clone();
+    let mut right = lists.right.clone();
+    left.sort_unstable();
+    right.sort_unstable();
 
-    left.sort();
-    right.sort();
-
-    let sum = left
+    Ok(left
         .iter()
         .zip(right.iter())
-        .fold(0, |acc, (left, right)| acc + (left - right).abs() as usize);
+        .map(|(left, right)| left.abs_diff(*right))
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::parser::parse;
 
-    dbg!(sum);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part1.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(
+            fixture.expected,
+            process(&parse(&fixture.input)?)?.to_string()
+        );
+        Ok(())
+    }
 }
@@ -1,26 +1,38 @@
-use std::collections::HashMap;
+use itertools::Itertools;
 
-fn main() {
-    let input = include_str!("../input.txt");
+use crate::custom_error::AocError;
+use crate::parser::Lists;
 
-    let (mut left, right) = input.lines().fold(
-        (Vec::new(), HashMap::new()),
-        |(mut left, mut right), line| {
-            let (l, r) = line.split_once("   ").unwrap();
-            left.push(l.parse::<isize>().unwrap());
+#[tracing::instrument(skip_all)]
+pub fn process(lists: &Lists) -> miette::Result<u64, AocError> {
+    let counts = lists.right.iter().counts();
 
-            let count = right.entry(r.parse::<isize>().unwrap()).or_default();
-            *count += 1;
+    Ok(lists
+        .left
+        .iter()
+        .map(|number| number * counts.get(number).copied().unwrap_or(0) as u64)
+        .sum())
+}
 
-            (left, right)
-        },
-    );
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
 
-    left.sort();
+    use aoc_input::Fixture;
+    use rstest::rstest;
 
-    let result = left.iter().fold(0, |acc, number| {
-        acc + number * right.get(number).unwrap_or(&0)
-    });
+    use super::*;
+    use crate::parser::parse;
 
-    dbg!(result);
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part2.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(
+            fixture.expected,
+            process(&parse(&fixture.input)?)?.to_string()
+        );
+        Ok(())
+    }
 }
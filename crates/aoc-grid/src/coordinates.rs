@@ -0,0 +1,121 @@
+use std::fmt;
+
+/// Position of a cell, `x` grows to the right and `y` grows downwards.
+///
+/// Coordinates are signed so that stepping off the grid can be represented
+/// and checked against its bounds.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct Coordinates {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Coordinates {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// The adjacent coordinates in `direction`, regardless of any bounds.
+    pub fn next(&self, direction: Direction) -> Coordinates {
+        let (dx, dy) = direction.offset();
+        Coordinates {
+            x: self.x + dx,
+            y: self.y + dy,
+        }
+    }
+
+    pub fn manhattan_distance(&self, other: &Coordinates) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl fmt::Display for Coordinates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(&self) -> (i32, i32) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        self.turn_left().opposite()
+    }
+
+    /// The two directions perpendicular to this one.
+    pub fn orthogonal(&self) -> [Direction; 2] {
+        [self.turn_left(), self.turn_right()]
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        !self.is_vertical()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() {
+        let origin = Coordinates::new(0, 0);
+        assert_eq!(Coordinates::new(0, -1), origin.next(Direction::Up));
+        assert_eq!(Coordinates::new(1, 0), origin.next(Direction::Right));
+    }
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.opposite(), direction.turn_left().turn_left());
+            assert!(direction
+                .orthogonal()
+                .iter()
+                .all(|other| other.is_vertical() != direction.is_vertical()));
+        }
+    }
+}
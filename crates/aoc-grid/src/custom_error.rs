@@ -0,0 +1,26 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::Coordinates;
+
+#[derive(Error, Diagnostic, Debug, PartialEq, Eq)]
+pub enum GridError {
+    #[error("grid has no cells")]
+    #[diagnostic(code(aoc_grid::empty))]
+    Empty,
+
+    #[error("row {row} has {found} cells, expected {expected}")]
+    #[diagnostic(code(aoc_grid::ragged))]
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("unexpected character {character:?} at {coordinates}")]
+    #[diagnostic(code(aoc_grid::unexpected_character))]
+    UnexpectedCharacter {
+        character: char,
+        coordinates: Coordinates,
+    },
+}
@@ -0,0 +1,351 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Coordinates, Direction, GridError};
+
+/// Offsets of the 8 cells surrounding a cell, clockwise from the top left.
+const SURROUNDING: [(i32, i32); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A dense, rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let height = rows.len();
+        let width = rows.first().map(Vec::len).ok_or(GridError::Empty)?;
+        if width == 0 {
+            return Err(GridError::Empty);
+        }
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (row, cells_in_row) in rows.into_iter().enumerate() {
+            if cells_in_row.len() != width {
+                return Err(GridError::Ragged {
+                    row,
+                    expected: width,
+                    found: cells_in_row.len(),
+                });
+            }
+            cells.extend(cells_in_row);
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Parses one row per line, mapping every character with `parse_cell`.
+    ///
+    /// Leading and trailing empty lines are ignored, spaces are cells like
+    /// any other character.
+    pub fn parse(input: &str, parse_cell: impl Fn(char) -> Option<T>) -> Result<Self, GridError> {
+        let lines: Vec<&str> = input.lines().skip_while(|line| line.is_empty()).collect();
+        let height = lines
+            .iter()
+            .rposition(|line| !line.is_empty())
+            .map_or(0, |last| last + 1);
+        let rows = lines[..height]
+            .iter()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, character)| {
+                        parse_cell(character).ok_or(GridError::UnexpectedCharacter {
+                            character,
+                            coordinates: Coordinates::new(x as i32, y as i32),
+                        })
+                    })
+                    .collect::<Result<Vec<T>, GridError>>()
+            })
+            .collect::<Result<Vec<Vec<T>>, GridError>>()?;
+
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coordinates: &Coordinates) -> bool {
+        (0..self.width as i32).contains(&coordinates.x)
+            && (0..self.height as i32).contains(&coordinates.y)
+    }
+
+    fn index_of(&self, coordinates: &Coordinates) -> Option<usize> {
+        self.contains(coordinates)
+            .then(|| coordinates.y as usize * self.width + coordinates.x as usize)
+    }
+
+    fn coordinates_of(&self, index: usize) -> Coordinates {
+        Coordinates::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub fn get(&self, coordinates: &Coordinates) -> Option<&T> {
+        self.index_of(coordinates).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, coordinates: &Coordinates) -> Option<&mut T> {
+        self.index_of(coordinates)
+            .map(|index| &mut self.cells[index])
+    }
+
+    /// The adjacent coordinates in `direction`, if they are still on the grid.
+    pub fn next(&self, coordinates: &Coordinates, direction: Direction) -> Option<Coordinates> {
+        let next = coordinates.next(direction);
+        self.contains(&next).then_some(next)
+    }
+
+    /// Up to 4 orthogonally adjacent cells that are on the grid.
+    pub fn neighbours(
+        &self,
+        coordinates: &Coordinates,
+    ) -> impl Iterator<Item = (Coordinates, &T)> + '_ {
+        let coordinates = *coordinates;
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.next(&coordinates, direction))
+            .map(|next| (next, &self[next]))
+    }
+
+    /// Up to 8 adjacent cells, diagonals included, that are on the grid.
+    pub fn neighbours8(
+        &self,
+        coordinates: &Coordinates,
+    ) -> impl Iterator<Item = (Coordinates, &T)> + '_ {
+        let coordinates = *coordinates;
+        SURROUNDING
+            .into_iter()
+            .map(move |(dx, dy)| Coordinates::new(coordinates.x + dx, coordinates.y + dy))
+            .filter_map(|next| self.get(&next).map(|cell| (next, cell)))
+    }
+
+    /// Every cell with its coordinates, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinates, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.coordinates_of(index), cell))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T> + '_> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Builds a `width` x `height` grid where every cell comes from `cell_at`.
+    fn from_fn(width: usize, height: usize, cell_at: impl Fn(Coordinates) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Coordinates::new(x as i32, y as i32)))
+            .map(cell_at)
+            .collect();
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |Coordinates { x, y }| {
+            self[Coordinates::new(y, x)].clone()
+        })
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height as i32;
+        Self::from_fn(self.height, self.width, |Coordinates { x, y }| {
+            self[Coordinates::new(y, height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let width = self.width as i32;
+        Self::from_fn(self.height, self.width, |Coordinates { x, y }| {
+            self[Coordinates::new(width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid keeping every character as is.
+    pub fn parse_chars(input: &str) -> Result<Self, GridError> {
+        Self::parse(input, Some)
+    }
+}
+
+impl<T> Index<Coordinates> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coordinates: Coordinates) -> &Self::Output {
+        self.get(&coordinates)
+            .unwrap_or_else(|| panic!("{coordinates} is out of the grid"))
+    }
+}
+
+impl<T> IndexMut<Coordinates> for Grid<T> {
+    fn index_mut(&mut self, coordinates: Coordinates) -> &mut Self::Output {
+        self.get_mut(&coordinates)
+            .unwrap_or_else(|| panic!("{coordinates} is out of the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "abc
+def";
+
+    #[test]
+    fn test_parse() -> Result<(), GridError> {
+        let grid = Grid::parse_chars(INPUT)?;
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&'f'), grid.get(&Coordinates::new(2, 1)));
+        assert_eq!(None, grid.get(&Coordinates::new(3, 1)));
+        assert_eq!(INPUT, grid.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_blank_lines() -> Result<(), GridError> {
+        let grid = Grid::parse_chars("\n\n .#\n.# \n\n")?;
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&' '), grid.get(&Coordinates::new(0, 0)));
+        assert_eq!(Some(&' '), grid.get(&Coordinates::new(2, 1)));
+        assert_eq!(
+            Err(GridError::UnexpectedCharacter {
+                character: 'x',
+                coordinates: Coordinates::new(1, 1)
+            }),
+            Grid::parse("\n 1\n1x\n", |c| c.to_digit(10).or((c == ' ').then_some(0)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Err(GridError::Empty), Grid::parse_chars("\n"));
+        assert_eq!(Err(GridError::Empty), Grid::parse_chars(""));
+        assert_eq!(
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            }),
+            Grid::parse_chars("abc\nde")
+        );
+        assert_eq!(
+            Err(GridError::UnexpectedCharacter {
+                character: 'x',
+                coordinates: Coordinates::new(1, 0)
+            }),
+            Grid::parse("1x", |c| c.to_digit(10))
+        );
+    }
+
+    #[test]
+    fn test_neighbours() -> Result<(), GridError> {
+        let grid = Grid::parse_chars(INPUT)?;
+
+        let corner: Vec<char> = grid
+            .neighbours(&Coordinates::new(0, 0))
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(vec!['b', 'd'], corner);
+
+        let middle: Vec<char> = grid
+            .neighbours8(&Coordinates::new(1, 0))
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(vec!['c', 'f', 'e', 'd', 'a'], middle);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rows_and_columns() -> Result<(), GridError> {
+        let grid = Grid::parse_chars(INPUT)?;
+
+        assert_eq!(Some(&['d', 'e', 'f'][..]), grid.row(1));
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(vec!["ad", "be", "cf"], columns);
+        assert_eq!(None, grid.column(3).map(|column| column.count()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_transformations() -> Result<(), GridError> {
+        let grid = Grid::parse_chars(INPUT)?;
+
+        assert_eq!("ad\nbe\ncf", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc", grid.rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad", grid.rotate_counter_clockwise().to_string());
+        assert_eq!(grid, grid.rotate_clockwise().rotate_counter_clockwise());
+        Ok(())
+    }
+}
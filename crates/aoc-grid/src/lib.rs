@@ -0,0 +1,7 @@
+mod coordinates;
+mod custom_error;
+mod grid;
+
+pub use coordinates::{Coordinates, Direction};
+pub use custom_error::GridError;
+pub use grid::Grid;
@@ -0,0 +1,335 @@
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Environment variable used to pick the input when no argument can be
+/// passed, e.g. under `cargo bench`.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Where a solver reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// An explicit file.
+    File(PathBuf),
+    /// Standard input, selected with `-`.
+    Stdin,
+    /// The day's `input.txt`, or its `input-partN.txt` override.
+    Default,
+}
+
+impl Source {
+    /// `-` selects stdin, any other value is a path and no value falls back
+    /// to the day's own input file.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None | Some("") => Source::Default,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Uses the first command line argument.
+    pub fn from_args() -> Self {
+        Self::from_arg(std::env::args().nth(1).as_deref())
+    }
+
+    /// Uses the `AOC_INPUT` environment variable.
+    pub fn from_env() -> Self {
+        Self::from_arg(std::env::var(INPUT_ENV).ok().as_deref())
+    }
+
+    pub fn read(&self, day_dir: impl AsRef<Path>, part: u8) -> std::io::Result<String> {
+        match self {
+            Source::File(path) => std::fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::Default => std::fs::read_to_string(default_path(day_dir, part)),
+        }
+    }
+}
+
+/// The input shared by both parts of a day.
+pub fn canonical_path(day_dir: impl AsRef<Path>) -> PathBuf {
+    day_dir.as_ref().join("input.txt")
+}
+
+/// An optional file used instead of the canonical input for one part only.
+pub fn override_path(day_dir: impl AsRef<Path>, part: u8) -> PathBuf {
+    day_dir.as_ref().join(format!("input-part{part}.txt"))
+}
+
+/// The input file a day uses for `part` when nothing else is specified.
+pub fn default_path(day_dir: impl AsRef<Path>, part: u8) -> PathBuf {
+    let part_override = override_path(&day_dir, part);
+    if part_override.exists() {
+        part_override
+    } else {
+        canonical_path(day_dir)
+    }
+}
+
+/// Something odd about the input files of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    MissingCanonical(PathBuf),
+    /// The `input1.txt`/`input2.txt` pair from before inputs were merged.
+    Legacy(PathBuf),
+    /// An override with the same content as the canonical input.
+    RedundantOverride(PathBuf),
+    /// Two files that are meant to hold the same input but don't.
+    Disagree(PathBuf, PathBuf),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::MissingCanonical(path) => write!(f, "{} is missing", path.display()),
+            Warning::Legacy(path) => write!(
+                f,
+                "{} is a legacy per-part input, move it to input.txt or input-partN.txt",
+                path.display()
+            ),
+            Warning::RedundantOverride(path) => write!(
+                f,
+                "{} is identical to input.txt and can be removed",
+                path.display()
+            ),
+            Warning::Disagree(a, b) => {
+                write!(f, "{} and {} disagree", a.display(), b.display())
+            }
+        }
+    }
+}
+
+/// Looks for missing, leftover or inconsistent input files in a day directory.
+pub fn check(day_dir: impl AsRef<Path>) -> std::io::Result<Vec<Warning>> {
+    let day_dir = day_dir.as_ref();
+    let mut warnings = Vec::new();
+
+    let canonical = canonical_path(day_dir);
+    let canonical_content = read_if_exists(&canonical)?;
+    if canonical_content.is_none() {
+        warnings.push(Warning::MissingCanonical(canonical.clone()));
+    }
+
+    for part in [1, 2] {
+        let part_override = override_path(day_dir, part);
+        if read_if_exists(&part_override)?.is_some_and(|content| {
+            canonical_content
+                .as_ref()
+                .is_some_and(|canonical| *canonical == content)
+        }) {
+            warnings.push(Warning::RedundantOverride(part_override));
+        }
+    }
+
+    let legacy = [day_dir.join("input1.txt"), day_dir.join("input2.txt")];
+    let legacy_content = [read_if_exists(&legacy[0])?, read_if_exists(&legacy[1])?];
+    for (path, content) in legacy.iter().zip(&legacy_content) {
+        if content.is_some() {
+            warnings.push(Warning::Legacy(path.clone()));
+        }
+    }
+    if let [Some(first), Some(second)] = &legacy_content {
+        if first != second {
+            warnings.push(Warning::Disagree(legacy[0].clone(), legacy[1].clone()));
+        }
+    }
+
+    Ok(warnings)
+}
+
+/// A worked example from the puzzle text, stored as `fixtures/<name>/input.txt`
+/// with the expected answer of each part in `fixtures/<name>/partN.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub input: String,
+    pub expected: String,
+}
+
+impl Fixture {
+    /// Loads the example the `partN.txt` at `answer` belongs to.
+    pub fn load(answer: impl AsRef<Path>) -> std::io::Result<Self> {
+        let answer = answer.as_ref();
+        Ok(Self {
+            input: std::fs::read_to_string(answer.with_file_name("input.txt"))?,
+            expected: std::fs::read_to_string(answer)?.trim().to_string(),
+        })
+    }
+
+    /// The `fixtures/*/partN.txt` of a day, in name order.
+    pub fn answers(day_dir: impl AsRef<Path>, part: u8) -> std::io::Result<Vec<PathBuf>> {
+        let fixtures = day_dir.as_ref().join("fixtures");
+        let mut answers = Vec::new();
+        for entry in std::fs::read_dir(fixtures)? {
+            let answer = entry?.path().join(format!("part{part}.txt"));
+            if answer.is_file() {
+                answers.push(answer);
+            }
+        }
+        answers.sort();
+        Ok(answers)
+    }
+}
+
+fn read_if_exists(path: &Path) -> std::io::Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::Default, Source::from_arg(None));
+        assert_eq!(Source::Default, Source::from_arg(Some("")));
+        assert_eq!(Source::Stdin, Source::from_arg(Some("-")));
+        assert_eq!(
+            Source::File(PathBuf::from("stress.txt")),
+            Source::from_arg(Some("stress.txt"))
+        );
+    }
+
+    #[test]
+    fn test_read_default() -> std::io::Result<()> {
+        let day_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../2023/rust/day-06");
+        let expected = std::fs::read_to_string(day_dir.join("input.txt"))?;
+        assert_eq!(expected, Source::Default.read(&day_dir, 2)?);
+        Ok(())
+    }
+
+    /// A directory of a test, removed once the test is done with it, even
+    /// when it fails.
+    struct DayDir(PathBuf);
+
+    impl std::ops::Deref for DayDir {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl AsRef<Path> for DayDir {
+        fn as_ref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for DayDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn day_dir(name: &str, files: &[(&str, &str)]) -> std::io::Result<DayDir> {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir)?;
+        for (file, content) in files {
+            let path = dir.join(file);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, content)?;
+        }
+        Ok(DayDir(dir))
+    }
+
+    #[test]
+    fn test_part_override() -> std::io::Result<()> {
+        let dir = day_dir(
+            "override",
+            &[("input.txt", "shared"), ("input-part2.txt", "other")],
+        )?;
+        assert_eq!("shared", Source::Default.read(&dir, 1)?);
+        assert_eq!("other", Source::Default.read(&dir, 2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixture() -> std::io::Result<()> {
+        let dir = day_dir("fixture", &[("input.txt", "1\n2\n"), ("part1.txt", "3\n")])?;
+        assert_eq!(
+            Fixture {
+                input: "1\n2\n".to_string(),
+                expected: "3".to_string()
+            },
+            Fixture::load(dir.join("part1.txt"))?
+        );
+        assert!(Fixture::load(dir.join("part2.txt")).is_err());
+
+        let day_dir = day_dir(
+            "answers",
+            &[
+                ("input.txt", "1\n"),
+                ("fixtures/example-2/input.txt", "2\n"),
+                ("fixtures/example-2/part1.txt", "2\n"),
+                ("fixtures/example-1/input.txt", "1\n"),
+                ("fixtures/example-1/part1.txt", "1\n"),
+                ("fixtures/example-1/part2.txt", "1\n"),
+                ("fixtures/notes.txt", "not a fixture\n"),
+            ],
+        )?;
+        let names = |part| -> std::io::Result<Vec<String>> {
+            Ok(Fixture::answers(&day_dir, part)?
+                .iter()
+                .map(|answer| answer.strip_prefix(&day_dir).unwrap().display().to_string())
+                .collect())
+        };
+        assert_eq!(
+            vec![
+                "fixtures/example-1/part1.txt",
+                "fixtures/example-2/part1.txt"
+            ],
+            names(1)?
+        );
+        assert_eq!(vec!["fixtures/example-1/part2.txt"], names(2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check() -> std::io::Result<()> {
+        let dir = day_dir(
+            "clean",
+            &[("input.txt", "shared"), ("input-part2.txt", "other")],
+        )?;
+        assert_eq!(Vec::<Warning>::new(), check(&dir)?);
+
+        let dir = day_dir(
+            "legacy",
+            &[
+                ("input1.txt", "first"),
+                ("input2.txt", "second"),
+                ("input-part1.txt", ""),
+            ],
+        )?;
+        assert_eq!(
+            vec![
+                Warning::MissingCanonical(dir.join("input.txt")),
+                Warning::Legacy(dir.join("input1.txt")),
+                Warning::Legacy(dir.join("input2.txt")),
+                Warning::Disagree(dir.join("input1.txt"), dir.join("input2.txt")),
+            ],
+            check(&dir)?
+        );
+
+        let dir = day_dir(
+            "redundant",
+            &[("input.txt", "shared"), ("input-part1.txt", "shared")],
+        )?;
+        assert_eq!(
+            vec![Warning::RedundantOverride(dir.join("input-part1.txt"))],
+            check(&dir)?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use miette::SourceSpan;
+use nom::error::ErrorKind;
+use nom::{Offset, Parser};
+use nom_locate::LocatedSpan;
+use nom_supreme::error::{BaseErrorKind, ErrorTree, StackContext};
+
+/// Input type for parsers that need to report where they failed.
+pub type Span<'a> = LocatedSpan<&'a str>;
+
+pub type IResult<'a, O> = nom::IResult<Span<'a>, O, ErrorTree<Span<'a>>>;
+
+/// Where and why parsing the puzzle input failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub span: SourceSpan,
+    pub message: String,
+}
+
+impl Failure {
+    fn at(offset: usize, remaining: usize, message: String) -> Self {
+        Self {
+            span: (offset, remaining.min(1)).into(),
+            message,
+        }
+    }
+}
+
+/// Turns a parser error into a [`Failure`] located in the original input.
+pub trait Locate {
+    fn locate(self, input: &str) -> Failure;
+}
+
+impl Locate for Failure {
+    fn locate(self, _input: &str) -> Failure {
+        self
+    }
+}
+
+impl Locate for nom::Err<nom::error::Error<&str>> {
+    fn locate(self, input: &str) -> Failure {
+        match self {
+            nom::Err::Incomplete(_) => incomplete(input),
+            nom::Err::Error(error) | nom::Err::Failure(error) => Failure::at(
+                input.offset(error.input),
+                error.input.len(),
+                describe_kind(error.code),
+            ),
+        }
+    }
+}
+
+impl Locate for nom::Err<ErrorTree<Span<'_>>> {
+    fn locate(self, input: &str) -> Failure {
+        match self {
+            nom::Err::Incomplete(_) => incomplete(input),
+            nom::Err::Error(error) | nom::Err::Failure(error) => locate_tree(&error),
+        }
+    }
+}
+
+fn incomplete(input: &str) -> Failure {
+    Failure::at(input.len(), 0, "unexpected end of input".to_string())
+}
+
+fn describe_kind(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => "expected a number".to_string(),
+        ErrorKind::Tag => "unexpected text".to_string(),
+        ErrorKind::Eof => "expected end of input".to_string(),
+        kind => format!("failed to parse {}", kind.description()),
+    }
+}
+
+/// Follows the error tree down to the furthest failure, collecting the
+/// contexts it was raised in on the way.
+fn locate_tree(error: &ErrorTree<Span>) -> Failure {
+    match error {
+        ErrorTree::Base { location, kind } => {
+            let message = match kind {
+                BaseErrorKind::Kind(kind) => describe_kind(*kind),
+                BaseErrorKind::External(error) => error.to_string(),
+                kind => kind.to_string(),
+            };
+            Failure::at(location.location_offset(), location.len(), message)
+        }
+        ErrorTree::Stack { base, contexts } => {
+            let mut failure = locate_tree(base);
+            for (_, context) in contexts {
+                if let StackContext::Context(context) = context {
+                    failure.message = format!("{} in {context}", failure.message);
+                }
+            }
+            failure
+        }
+        ErrorTree::Alt(siblings) => siblings
+            .iter()
+            .map(locate_tree)
+            .max_by_key(|failure| failure.span.offset())
+            .unwrap_or_else(|| Failure::at(0, 0, "no alternative matched".to_string())),
+    }
+}
+
+/// Runs `parser` on the whole input, allowing only trailing whitespace to be
+/// left over.
+pub fn final_parse<'a, O>(
+    mut parser: impl Parser<Span<'a>, O, ErrorTree<Span<'a>>>,
+    input: &'a str,
+) -> Result<O, Failure> {
+    let (remaining, output) = parser
+        .parse(Span::new(input))
+        .map_err(|error| error.locate(input))?;
+    check_remaining(input, remaining.fragment())?;
+    Ok(output)
+}
+
+/// [`final_parse`] for parsers on a plain `&str`, with nom's own errors.
+pub fn final_parse_str<'a, O>(
+    mut parser: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+    input: &'a str,
+) -> Result<O, Failure> {
+    let (remaining, output) = parser.parse(input).map_err(|error| error.locate(input))?;
+    check_remaining(input, remaining)?;
+    Ok(output)
+}
+
+fn check_remaining(input: &str, remaining: &str) -> Result<(), Failure> {
+    let trailing = remaining.trim_start();
+    if trailing.is_empty() {
+        Ok(())
+    } else {
+        Err(Failure::at(
+            input.offset(trailing),
+            trailing.len(),
+            "unexpected input".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete;
+    use nom::multi::separated_list1;
+    use nom::sequence::preceded;
+    use nom_supreme::tag::complete::tag;
+    use nom_supreme::ParserExt;
+
+    use super::*;
+
+    fn numbers(input: Span) -> IResult<Vec<u32>> {
+        preceded(
+            tag("numbers: "),
+            separated_list1(tag(" "), complete::u32.context("number")),
+        )
+        .context("numbers")
+        .parse(input)
+    }
+
+    #[test]
+    fn test_final_parse() {
+        assert_eq!(Ok(vec![1, 2, 3]), final_parse(numbers, "numbers: 1 2 3\n"));
+    }
+
+    #[test]
+    fn test_final_parse_errors() {
+        assert_eq!(
+            Err(Failure {
+                span: (0, 1).into(),
+                message: "expected \"numbers: \" in numbers".to_string(),
+            }),
+            final_parse(numbers, "nombres: 1 2 3")
+        );
+        assert_eq!(
+            Err(Failure {
+                span: (13, 1).into(),
+                message: "unexpected input".to_string(),
+            }),
+            final_parse(numbers, "numbers: 1 2 x")
+        );
+    }
+
+    #[test]
+    fn test_final_parse_str() {
+        let numbers = || separated_list1(complete::space1, complete::u32);
+        assert_eq!(Ok(vec![1, 2, 3]), final_parse_str(numbers(), "1 2 3\n"));
+        assert_eq!(
+            Err(Failure {
+                span: (4, 1).into(),
+                message: "unexpected input".to_string(),
+            }),
+            final_parse_str(numbers(), "1 2\nx 3")
+        );
+    }
+
+    #[test]
+    fn test_locate_nom_error() {
+        let input = "12 ab";
+        let error = preceded(
+            complete::u32::<_, nom::error::Error<&str>>,
+            preceded(complete::space1, complete::u32),
+        )(input)
+        .unwrap_err();
+
+        assert_eq!(
+            Failure {
+                span: (3, 1).into(),
+                message: "expected a number".to_string(),
+            },
+            error.locate(input)
+        );
+    }
+}
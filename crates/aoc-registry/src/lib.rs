@@ -0,0 +1,149 @@
+//! Every solver of the workspace, shared by the `aoc` runner and the `www`
+//! playground.
+
+use std::path::{Path, PathBuf};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug)]
+#[error("no solver registered for {year} day {day} part {part}")]
+#[diagnostic(
+    code(aoc::unknown_solver),
+    help("registered solvers are listed in crates/aoc-registry/src/lib.rs")
+)]
+pub struct UnknownSolver {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+}
+
+/// How the panic message of a part that still ends in `todo!()` starts.
+pub const TODO_PANIC: &str = "not yet implemented";
+
+/// A single part of a day crate.
+#[derive(Debug, Clone, Copy)]
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub process: fn(&str) -> miette::Result<String>,
+}
+
+impl Solver {
+    /// Directory of the day crate this solver comes from.
+    pub fn day_dir(&self) -> PathBuf {
+        day_dir(self.year, self.day)
+    }
+}
+
+/// Directory of the crate of `day` in `year`, whether it is registered yet or
+/// not.
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    workspace_dir()
+        .join(year.to_string())
+        .join("rust")
+        .join(format!("day-{day:02}"))
+}
+
+/// Root of the workspace the registry was built in.
+fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .expect("the registry lives in crates/ of the workspace")
+}
+
+/// Registers both parts of a day crate implementing [`aoc_solution::Solution`].
+macro_rules! solution {
+    ($year:literal, $day:literal, $solution:path) => {
+        [
+            Solver {
+                year: $year,
+                day: $day,
+                part: 1,
+                process: aoc_solution::process_part1::<$solution>,
+            },
+            Solver {
+                year: $year,
+                day: $day,
+                part: 2,
+                process: aoc_solution::process_part2::<$solution>,
+            },
+        ]
+    };
+}
+
+/// Registers both parts of a day crate still exposing `partN::process`.
+macro_rules! day {
+    ($year:literal, $day:literal, $krate:ident) => {
+        [
+            Solver {
+                year: $year,
+                day: $day,
+                part: 1,
+                process: |input| Ok($krate::part1::process(input)?),
+            },
+            Solver {
+                year: $year,
+                day: $day,
+                part: 2,
+                process: |input| Ok($krate::part2::process(input)?),
+            },
+        ]
+    };
+}
+
+static SOLVERS: &[[Solver; 2]] = &[
+    day!(2023, 1, y2023_day_01),
+    solution!(2023, 2, y2023_day_02::Day02),
+    solution!(2023, 3, y2023_day_03::Day03),
+    solution!(2023, 4, y2023_day_04::Day04),
+    solution!(2023, 5, y2023_day_05::Day05),
+    solution!(2023, 6, y2023_day_06::Day06),
+    day!(2023, 7, y2023_day_07),
+    solution!(2023, 8, y2023_day_08::Day08),
+    solution!(2023, 9, y2023_day_09::Day09),
+    day!(2023, 10, y2023_day_10),
+    day!(2023, 11, y2023_day_11),
+    solution!(2023, 12, y2023_day_12::Day12),
+    day!(2023, 13, y2023_day_13),
+    day!(2023, 14, y2023_day_14),
+    day!(2023, 15, y2023_day_15),
+    solution!(2023, 16, y2023_day_16::Day16),
+    solution!(2023, 17, y2023_day_17::Day17),
+    day!(2023, 18, y2023_day_18),
+    solution!(2023, 19, y2023_day_19::Day19),
+    solution!(2024, 1, y2024_day_01::Day01),
+];
+
+pub fn solvers() -> impl Iterator<Item = &'static Solver> {
+    SOLVERS.iter().flatten()
+}
+
+pub fn find(year: u16, day: u8, part: u8) -> Result<&'static Solver, UnknownSolver> {
+    solvers()
+        .find(|solver| solver.year == year && solver.day == day && solver.part == part)
+        .ok_or(UnknownSolver { year, day, part })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        let solver = find(2023, 5, 2).unwrap();
+        assert_eq!((2023, 5, 2), (solver.year, solver.day, solver.part));
+        assert!(solver.day_dir().ends_with("2023/rust/day-05"));
+        assert!(solver.day_dir().is_dir());
+    }
+
+    #[test]
+    fn test_find_unknown() {
+        assert!(matches!(
+            find(2023, 26, 1),
+            Err(UnknownSolver { day: 26, .. })
+        ));
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use miette::Diagnostic;
+
+/// A day of the puzzle: the input is parsed once into a model that both
+/// parts are computed from.
+pub trait Solution {
+    /// Parsed puzzle input shared by both parts.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+    type Error: Diagnostic + Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Self::Error>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Self::Error>;
+}
+
+/// Parses `input` and answers part 1, formatted the way it is submitted.
+pub fn process_part1<S: Solution>(input: &str) -> miette::Result<String> {
+    let input = S::parse(input)?;
+    Ok(S::part1(&input)?.to_string())
+}
+
+/// Parses `input` and answers part 2, formatted the way it is submitted.
+pub fn process_part2<S: Solution>(input: &str) -> miette::Result<String> {
+    let input = S::parse(input)?;
+    Ok(S::part2(&input)?.to_string())
+}
+
+/// Answers both parts from a single parse of `input`.
+pub fn process<S: Solution>(input: &str) -> miette::Result<(String, String)> {
+    let input = S::parse(input)?;
+    Ok((S::part1(&input)?.to_string(), S::part2(&input)?.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(thiserror::Error, Diagnostic, Debug)]
+    #[error("not a number")]
+    struct NotANumber;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+        type Answer1 = i64;
+        type Answer2 = String;
+        type Error = NotANumber;
+
+        fn parse(input: &str) -> Result<Vec<i64>, NotANumber> {
+            input
+                .split_whitespace()
+                .map(|number| number.parse().map_err(|_| NotANumber))
+                .collect()
+        }
+
+        fn part1(numbers: &Vec<i64>) -> Result<i64, NotANumber> {
+            Ok(numbers.iter().sum())
+        }
+
+        fn part2(numbers: &Vec<i64>) -> Result<String, NotANumber> {
+            Ok(numbers.iter().rev().map(i64::to_string).collect())
+        }
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!("2", process_part1::<Sum>("3 -1")?);
+        assert_eq!("-13", process_part2::<Sum>("3 -1")?);
+        assert_eq!(
+            ("6".to_string(), "321".to_string()),
+            process::<Sum>("1 2 3")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = process_part1::<Sum>("1 two").unwrap_err();
+        assert_eq!("not a number", error.to_string());
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::custom_error::AocError;
+
+/// Accepted answers, keyed by year then zero-padded day:
+///
+/// ```toml
+/// [2023.05]
+/// part1 = "107430936"
+/// part2 = "23738616"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, DayAnswers>>);
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl DayAnswers {
+    fn part(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn part_mut(&mut self, part: u8) -> Option<&mut Option<String>> {
+        match part {
+            1 => Some(&mut self.part1),
+            2 => Some(&mut self.part2),
+            _ => None,
+        }
+    }
+}
+
+impl Answers {
+    /// The checked-in `answers.toml` at the root of the workspace.
+    pub fn default_path() -> PathBuf {
+        crate::workspace_dir().join("answers.toml")
+    }
+
+    /// Loads `path`, a missing file has no answers yet.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|source| AocError::InvalidAnswers {
+                path: path.to_path_buf(),
+                source,
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let content = toml::to_string(self).expect("answers are plain strings");
+        Ok(fs::write(path, content)?)
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.0
+            .get(&year.to_string())?
+            .get(&format!("{day:02}"))?
+            .part(part)
+            .map(String::as_str)
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: u8, answer: String) {
+        let day_answers = self
+            .0
+            .entry(year.to_string())
+            .or_default()
+            .entry(format!("{day:02}"))
+            .or_default();
+        if let Some(slot) = day_answers.part_mut(part) {
+            *slot = Some(answer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"[2023.05]
+part1 = "107430936"
+
+[2023.06]
+part1 = "3316275"
+part2 = "27102791"
+"#;
+
+    #[test]
+    fn test_get() {
+        let answers: Answers = toml::from_str(ANSWERS).unwrap();
+        assert_eq!(Some("107430936"), answers.get(2023, 5, 1));
+        assert_eq!(None, answers.get(2023, 5, 2));
+        assert_eq!(Some("27102791"), answers.get(2023, 6, 2));
+        assert_eq!(None, answers.get(2024, 6, 2));
+    }
+
+    #[test]
+    fn test_record() {
+        let mut answers = Answers::default();
+        answers.record(2023, 6, 2, "27102791".to_string());
+        answers.record(2023, 6, 1, "3316275".to_string());
+        answers.record(2023, 5, 1, "107430936".to_string());
+        answers.record(2023, 5, 3, "ignored".to_string());
+
+        assert_eq!(ANSWERS, toml::to_string(&answers).unwrap());
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::custom_error::AocError;
+
+/// One row of divan's table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    /// Name of the benchmark, `parse`, `part1`, `part2_nom`...
+    pub part: String,
+    pub median_ns: f64,
+    pub mean_ns: f64,
+    pub samples: u32,
+}
+
+/// A measurement along with where and when it was taken, one per line of
+/// the history file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    #[serde(flatten)]
+    pub measurement: Measurement,
+    pub machine: String,
+    pub rev: String,
+    pub recorded_at: u64,
+}
+
+impl Record {
+    fn key(&self) -> (u16, u8, &str) {
+        let measurement = &self.measurement;
+        (measurement.year, measurement.day, &measurement.part)
+    }
+}
+
+/// Reads divan's human output. Groups are expected to be named after the
+/// bench target, `day_05` or `y2023_day_05`, `year` is used for the former.
+pub fn parse_divan(output: &str, year: u16) -> Vec<Measurement> {
+    let mut measurements = Vec::new();
+    let mut group = None;
+
+    for line in output.lines() {
+        if line.contains("fastest") && line.contains('│') {
+            group = line
+                .split_whitespace()
+                .next()
+                .and_then(|name| parse_group(name, year));
+            continue;
+        }
+        let Some((year, day)) = group else {
+            continue;
+        };
+
+        let row = line.trim_start_matches(['│', '├', '╰', '─', ' ']);
+        let columns: Vec<&str> = row.split('│').map(str::trim).collect();
+        let [first, _slowest, median, mean, samples, _iters] = columns[..] else {
+            continue;
+        };
+        let Some((part, _fastest)) = first.split_once(char::is_whitespace) else {
+            continue;
+        };
+
+        if let (Some(median_ns), Some(mean_ns), Ok(samples)) = (
+            parse_duration(median),
+            parse_duration(mean),
+            samples.parse(),
+        ) {
+            measurements.push(Measurement {
+                year,
+                day,
+                part: part.to_string(),
+                median_ns,
+                mean_ns,
+                samples,
+            });
+        }
+    }
+
+    measurements
+}
+
+fn parse_group(name: &str, default_year: u16) -> Option<(u16, u8)> {
+    let (year, name) = match name.strip_prefix('y').and_then(|name| name.split_once('_')) {
+        Some((year, name)) => (year.parse().ok()?, name),
+        None => (default_year, name),
+    };
+    let day = name.strip_prefix("day_")?.parse().ok()?;
+    Some((year, day))
+}
+
+/// `37.24 µs` in nanoseconds.
+fn parse_duration(duration: &str) -> Option<f64> {
+    let (value, unit) = duration.split_once(' ')?;
+    let scale = match unit.trim() {
+        "ns" => 1.0,
+        "µs" | "us" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+    Some(value.parse::<f64>().ok()? * scale)
+}
+
+/// Every record of the JSON lines history at `path`, oldest first.
+pub fn load(path: &Path) -> Result<Vec<Record>, AocError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error.into()),
+    };
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).map_err(|source| AocError::InvalidBenchHistory {
+                path: path.to_path_buf(),
+                line: index + 1,
+                source,
+            })
+        })
+        .collect()
+}
+
+pub fn append(path: &Path, records: &[Record]) -> Result<(), AocError> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for record in records {
+        let line = serde_json::to_string(record).expect("records serialize to JSON");
+        writeln!(file, "{line}")?;
+    }
+    Ok(())
+}
+
+/// The median of a benchmark at the baseline and at the revision it is
+/// compared with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub part: String,
+    pub baseline_ns: f64,
+    pub current_ns: f64,
+    pub regressed: bool,
+}
+
+impl Comparison {
+    pub fn change(&self) -> f64 {
+        (self.current_ns - self.baseline_ns) / self.baseline_ns * 100.0
+    }
+}
+
+/// Compares the latest records of `machine` at revision `baseline` with the
+/// ones at `current`, or with the latest records when `current` is `None`.
+/// A median more than `threshold` percent slower is a regression.
+pub fn compare(
+    history: &[Record],
+    machine: &str,
+    baseline: &str,
+    current: Option<&str>,
+    threshold: f64,
+) -> Vec<Comparison> {
+    let latest = |rev: Option<&str>| {
+        history
+            .iter()
+            .filter(|record| record.machine == machine)
+            .filter(|record| rev.is_none_or(|rev| record.rev.starts_with(rev)))
+            .map(|record| (record.key(), record))
+            .collect::<BTreeMap<_, _>>()
+    };
+    let baselines = latest(Some(baseline));
+    let currents = latest(current);
+
+    baselines
+        .into_iter()
+        .filter_map(|(key, baseline)| {
+            let current = currents.get(&key)?;
+            let (year, day, part) = key;
+            let mut comparison = Comparison {
+                year,
+                day,
+                part: part.to_string(),
+                baseline_ns: baseline.measurement.median_ns,
+                current_ns: current.measurement.median_ns,
+                regressed: false,
+            };
+            comparison.regressed = comparison.change() > threshold;
+            Some(comparison)
+        })
+        .collect()
+}
+
+/// Column titles lined up with the rows of [`Comparison`]'s `Display`.
+pub fn header() -> String {
+    format!(
+        "{:>4}  {:>3}  {:<20}  {:>12}  {:>12}  {:>8}  {}",
+        "year", "day", "part", "baseline", "current", "change", "status"
+    )
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>4}  {:>3}  {:<20}  {:>12}  {:>12}  {:>8}  {}",
+            self.year,
+            self.day,
+            self.part,
+            format!("{:.2?}", nanoseconds(self.baseline_ns)),
+            format!("{:.2?}", nanoseconds(self.current_ns)),
+            format!("{:+.1}%", self.change()),
+            if self.regressed { "REGRESSED" } else { "ok" }
+        )
+    }
+}
+
+fn nanoseconds(ns: f64) -> std::time::Duration {
+    std::time::Duration::from_nanos(ns.round() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = "Timer precision: 44 ns
+day_06    fastest       │ slowest       │ median        │ mean          │ samples │ iters
+├─ parse  360.6 ns      │ 481.6 ns      │ 397.8 ns      │ 397.8 ns      │ 100     │ 1600
+├─ part1  1.418 µs      │ 1.799 µs      │ 1.602 µs      │ 1.582 µs      │ 100     │ 400
+╰─ part2  135.1 ms      │ 250.1 ms      │ 193.9 ms      │ 189.1 ms      │ 100     │ 100
+
+y2024_day_01  fastest       │ slowest       │ median        │ mean          │ samples │ iters
+╰─ part1      1.5 s         │ 1.5 s         │ 1.5 s         │ 1.5 s         │ 1       │ 1
+";
+
+    fn record(part: &str, median_ns: f64, rev: &str) -> Record {
+        Record {
+            measurement: Measurement {
+                year: 2023,
+                day: 6,
+                part: part.to_string(),
+                median_ns,
+                mean_ns: median_ns,
+                samples: 100,
+            },
+            machine: "pc".to_string(),
+            rev: rev.to_string(),
+            recorded_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_parse_divan() {
+        let measurements = parse_divan(OUTPUT, 2023);
+        let summary: Vec<(u16, u8, &str, f64)> = measurements
+            .iter()
+            .map(|m| (m.year, m.day, m.part.as_str(), m.median_ns.round()))
+            .collect();
+        assert_eq!(
+            vec![
+                (2023, 6, "parse", 398.0),
+                (2023, 6, "part1", 1602.0),
+                (2023, 6, "part2", 193_900_000.0),
+                (2024, 1, "part1", 1_500_000_000.0),
+            ],
+            summary
+        );
+        assert_eq!(100, measurements[0].samples);
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = vec![
+            record("part1", 100.0, "aaaaaaa"),
+            record("part2", 100.0, "aaaaaaa"),
+            record("part1", 200.0, "bbbbbbb"),
+            record("part1", 105.0, "ccccccc"),
+            record("part2", 150.0, "ccccccc"),
+        ];
+
+        let latest = compare(&history, "pc", "aaa", None, 10.0);
+        assert_eq!(
+            vec![("part1", false), ("part2", true)],
+            latest
+                .iter()
+                .map(|c| (c.part.as_str(), c.regressed))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(50.0, latest[1].change());
+
+        let against = compare(&history, "pc", "aaa", Some("bbb"), 10.0);
+        assert_eq!(1, against.len());
+        assert!(against[0].regressed);
+
+        assert!(compare(&history, "mac", "aaa", None, 10.0).is_empty());
+    }
+}
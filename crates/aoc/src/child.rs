@@ -0,0 +1,80 @@
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Read};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// What a child process left behind.
+#[derive(Debug)]
+pub struct Finished {
+    /// `None` when it was killed after running out of time.
+    pub status: Option<ExitStatus>,
+    pub stdout: Vec<String>,
+    pub stderr: Vec<String>,
+    pub elapsed: Duration,
+}
+
+/// Runs `command` with piped output and kills it once `timeout` is reached.
+/// Only the last `tail` lines of each stream that pass `keep` are returned,
+/// trimmed.
+pub fn run(
+    command: &mut Command,
+    timeout: Duration,
+    tail: usize,
+    keep: fn(&str) -> bool,
+) -> io::Result<Finished> {
+    let start = Instant::now();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Solvers can be chatty, the pipes are drained as they go.
+    let stdout = last_lines(child.stdout.take(), tail, keep);
+    let stderr = last_lines(child.stderr.take(), tail, keep);
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if start.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+            break None;
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    Ok(Finished {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+        elapsed: start.elapsed(),
+    })
+}
+
+fn last_lines(
+    pipe: Option<impl Read + Send + 'static>,
+    tail: usize,
+    keep: fn(&str) -> bool,
+) -> thread::JoinHandle<Vec<String>> {
+    thread::spawn(move || {
+        let Some(pipe) = pipe else {
+            return Vec::new();
+        };
+        let mut lines = VecDeque::with_capacity(tail + 1);
+        for line in BufReader::new(pipe).lines().map_while(Result::ok) {
+            let line = line.trim();
+            if !line.is_empty() && keep(line) {
+                lines.push_back(line.to_string());
+                if lines.len() > tail {
+                    lines.pop_front();
+                }
+            }
+        }
+        lines.into()
+    })
+}
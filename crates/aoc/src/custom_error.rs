@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::submit::Verdict;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum AocError {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::io_error))]
+    IoError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    UnknownSolver(#[from] aoc_registry::UnknownSolver),
+
+    #[error("invalid answers file {}", path.display())]
+    #[diagnostic(code(aoc::invalid_answers))]
+    InvalidAnswers {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+
+    #[error("invalid guesses file {}", path.display())]
+    #[diagnostic(code(aoc::invalid_guesses))]
+    InvalidGuesses {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+
+    #[error("{failed} answer(s) did not match")]
+    #[diagnostic(code(aoc::verify_failed))]
+    VerifyFailed { failed: usize },
+
+    #[error("invalid benchmark record on line {line} of {}", path.display())]
+    #[diagnostic(code(aoc::invalid_bench_history))]
+    InvalidBenchHistory {
+        path: PathBuf,
+        line: usize,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("no benchmark recorded on {machine} at {rev}")]
+    #[diagnostic(
+        code(aoc::unknown_baseline),
+        help("record one with `cargo bench -q | aoc bench record` on that revision")
+    )]
+    UnknownBaseline { rev: String, machine: String },
+
+    #[error("failed to build the dhat-heap binaries")]
+    #[diagnostic(code(aoc::dhat_build_failed))]
+    DhatBuildFailed,
+
+    #[error("{} already exists and is registered", path.display())]
+    #[diagnostic(code(aoc::day_exists))]
+    DayExists { path: PathBuf },
+
+    #[error("cargo generate failed to create the day from daily-template")]
+    #[diagnostic(
+        code(aoc::generate_failed),
+        help("it is installed with `cargo install cargo-generate`")
+    )]
+    GenerateFailed,
+
+    #[error("a stage of the work cycle failed")]
+    #[diagnostic(code(aoc::work_failed))]
+    WorkFailed,
+
+    #[error("{regressed} benchmark(s) regressed")]
+    #[diagnostic(code(aoc::bench_regressed))]
+    BenchRegressed { regressed: usize },
+
+    #[error("no session token to download inputs with")]
+    #[diagnostic(
+        code(aoc::missing_session),
+        help("set AOC_SESSION (or pass --session) to the `session` cookie of adventofcode.com")
+    )]
+    MissingSession,
+
+    #[error("there is no crate for {year} day {day} at {}", path.display())]
+    #[diagnostic(
+        code(aoc::missing_day),
+        help("create it first with `aoc new {year} {day}`")
+    )]
+    MissingDay { year: u16, day: u8, path: PathBuf },
+
+    #[error("failed to download {url}: {reason}")]
+    #[diagnostic(code(aoc::fetch_failed))]
+    FetchFailed { url: String, reason: String },
+
+    #[error("unexpected response from the server: {message}")]
+    #[diagnostic(code(aoc::unexpected_response))]
+    UnexpectedResponse { message: String },
+
+    #[error("{answer} is {verdict}")]
+    #[diagnostic(code(aoc::wrong_answer))]
+    WrongAnswer { answer: String, verdict: Verdict },
+
+    #[error("{answer} is known to be {verdict}, it was not submitted")]
+    #[diagnostic(
+        code(aoc::known_wrong_answer),
+        help("answers the server rejected are kept in guesses.toml")
+    )]
+    KnownWrongAnswer { answer: String, verdict: Verdict },
+
+    #[error("the server wants {}s before the next answer", wait.as_secs())]
+    #[diagnostic(
+        code(aoc::rate_limited),
+        help("nothing was recorded, submit again later")
+    )]
+    RateLimited { wait: Duration },
+}
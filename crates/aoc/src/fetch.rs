@@ -0,0 +1,329 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::custom_error::AocError;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Identifies the tool to the Advent of Code servers, as they ask automated
+/// requests to.
+const USER_AGENT: &str = "github.com/pngouembe/advent-of-code aoc-runner";
+
+/// Minimum time between two requests to the puzzle server.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    /// A non-empty input was already there, nothing was requested.
+    Cached,
+}
+
+/// Talks to the puzzle server, waiting at least `interval` between requests.
+pub struct Client {
+    base_url: String,
+    session: String,
+    interval: Duration,
+    last_request: Option<SystemTime>,
+    /// File keeping the time of the last request across runs.
+    stamp: Option<PathBuf>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, interval: Duration) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            interval,
+            last_request: None,
+            stamp: None,
+        }
+    }
+
+    /// Keeps the time of the last request in `path`, so that the interval
+    /// holds across runs of the tool too.
+    pub fn with_stamp(mut self, path: PathBuf) -> Self {
+        self.stamp = Some(path);
+        self
+    }
+
+    /// When the previous request was sent, by this client or by any other
+    /// sharing its stamp.
+    fn last_request(&self) -> Option<SystemTime> {
+        let stamped = self.stamp.as_deref().and_then(|path| {
+            let millis = fs::read_to_string(path).ok()?.trim().parse().ok()?;
+            Some(UNIX_EPOCH + Duration::from_millis(millis))
+        });
+        stamped.max(self.last_request)
+    }
+
+    /// The input of `day`, straight from the server.
+    pub fn input(&mut self, year: u16, day: u8) -> Result<String, AocError> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        self.send(&url, None)
+    }
+
+    /// Posts `answer` to one part of `day` and returns the page the server
+    /// responds with.
+    pub fn submit(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocError> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        self.send(&url, Some(&[("level", &level), ("answer", answer)]))
+    }
+
+    /// GETs `url`, or POSTs `form` to it, once `interval` has passed since
+    /// the previous request.
+    fn send(&mut self, url: &str, form: Option<&[(&str, &str)]>) -> Result<String, AocError> {
+        if let Some(last) = self.last_request() {
+            // A stamp from the future, the clock moved back, waits it all.
+            let elapsed = last.elapsed().unwrap_or_default();
+            thread::sleep(self.interval.saturating_sub(elapsed));
+        }
+        let now = SystemTime::now();
+        self.last_request = Some(now);
+        if let Some(path) = &self.stamp {
+            let millis = now
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis();
+            fs::write(path, millis.to_string())?;
+        }
+
+        let failed = |reason: String| AocError::FetchFailed {
+            url: url.to_string(),
+            reason,
+        };
+        let request = match form {
+            Some(_) => ureq::post(url),
+            None => ureq::get(url),
+        }
+        .set("Cookie", &format!("session={}", self.session))
+        .set("User-Agent", USER_AGENT);
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        }
+        .map_err(|error| match error {
+            ureq::Error::Status(status, response) => {
+                let body = response.into_string().unwrap_or_default();
+                failed(format!("HTTP {status}: {}", body.trim()))
+            }
+            // The transport error repeats the url, only keep what went wrong.
+            ureq::Error::Transport(transport) => failed(match transport.message() {
+                Some(message) => format!("{}: {message}", transport.kind()),
+                None => transport.kind().to_string(),
+            }),
+        })?;
+        response
+            .into_string()
+            .map_err(|error| failed(error.to_string()))
+    }
+
+    /// Stores the input of `day` at `path` unless a non-empty one is already
+    /// there, `just create` leaves an empty file behind.
+    pub fn fetch(&mut self, year: u16, day: u8, path: &Path) -> Result<Fetched, AocError> {
+        if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Fetched::Cached);
+        }
+
+        let input = self.input(year, day)?;
+        fs::write(path, input)?;
+        Ok(Fetched::Downloaded)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::sync::mpsc;
+    use std::time::Instant;
+
+    use super::*;
+
+    /// A request as seen by [`serve`].
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Request {
+        pub line: String,
+        pub cookie: String,
+        pub body: String,
+    }
+
+    /// Answers each connection with `status` and `body`, and sends back the
+    /// requests it received.
+    pub fn serve(status: &str, body: &str) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end().to_string();
+                    if line.is_empty() {
+                        break;
+                    }
+                    headers.push(line);
+                }
+                let header = |name: &str| {
+                    headers
+                        .iter()
+                        .find_map(|line| line.strip_prefix(name))
+                        .unwrap_or_default()
+                        .to_string()
+                };
+                let length = header("Content-Length: ").parse().unwrap_or(0);
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+
+                let request = Request {
+                    line: headers[0].clone(),
+                    cookie: header("Cookie: "),
+                    body: String::from_utf8(body).unwrap(),
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+                if sender.send(request).is_err() {
+                    break;
+                }
+            }
+        });
+
+        (base_url, receiver)
+    }
+
+    /// A directory of a test, removed once the test is done with it, even
+    /// when it fails.
+    struct TempDir(PathBuf);
+
+    impl std::ops::Deref for TempDir {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn temp_dir(name: &str) -> TempDir {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+
+    #[test]
+    fn test_fetch() -> miette::Result<()> {
+        let (base_url, requests) = serve("200 OK", "seeds: 79 14 55 13\n");
+        let mut client = Client::new(&base_url, "cafe\n", Duration::ZERO);
+        let dir = temp_dir("download");
+        let path = dir.join("input.txt");
+
+        assert_eq!(Fetched::Downloaded, client.fetch(2023, 5, &path)?);
+        assert_eq!("seeds: 79 14 55 13\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(
+            Request {
+                line: "GET /2023/day/5/input HTTP/1.1".to_string(),
+                cookie: "session=cafe".to_string(),
+                body: String::new(),
+            },
+            requests.recv().unwrap()
+        );
+
+        assert_eq!(Fetched::Cached, client.fetch(2023, 5, &path)?);
+        assert!(requests.try_recv().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_empty_file() -> miette::Result<()> {
+        let (base_url, _requests) = serve("200 OK", "1\n");
+        let mut client = Client::new(&base_url, "cafe", Duration::ZERO);
+        let dir = temp_dir("empty");
+        let path = dir.join("input.txt");
+        fs::write(&path, "").unwrap();
+
+        assert_eq!(Fetched::Downloaded, client.fetch(2023, 5, &path)?);
+        assert_eq!("1\n", fs::read_to_string(&path).unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_error() {
+        let (base_url, _requests) = serve("400 Bad Request", "Please log in.");
+        let mut client = Client::new(&base_url, "stale", Duration::ZERO);
+        let dir = temp_dir("error");
+        let path = dir.join("input.txt");
+
+        let error = client.fetch(2023, 5, &path).unwrap_err();
+        assert!(
+            matches!(&error, AocError::FetchFailed { reason, .. } if reason == "HTTP 400: Please log in."),
+            "{error:?}"
+        );
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_submit() -> miette::Result<()> {
+        let (base_url, requests) = serve("200 OK", "<article>ok</article>");
+        let mut client = Client::new(&base_url, "cafe", Duration::ZERO);
+
+        assert_eq!("<article>ok</article>", client.submit(2023, 5, 2, "46")?);
+        let request = requests.recv().unwrap();
+        assert_eq!("POST /2023/day/5/answer HTTP/1.1", request.line);
+        assert_eq!("level=2&answer=46", request.body);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rate_limit() -> miette::Result<()> {
+        let (base_url, _requests) = serve("200 OK", "1\n");
+        let interval = Duration::from_millis(200);
+        let mut client = Client::new(&base_url, "cafe", interval);
+
+        let start = Instant::now();
+        client.input(2023, 5)?;
+        client.input(2023, 6)?;
+        assert!(start.elapsed() >= interval);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rate_limit_stamp() -> miette::Result<()> {
+        let (base_url, _requests) = serve("200 OK", "1\n");
+        let interval = Duration::from_millis(200);
+        let dir = temp_dir("stamp");
+        let stamp = dir.join("last-request");
+        let client = || Client::new(&base_url, "cafe", interval).with_stamp(stamp.clone());
+
+        let start = Instant::now();
+        client().input(2023, 5)?;
+        client().input(2023, 6)?;
+        assert!(start.elapsed() >= interval);
+        Ok(())
+    }
+}
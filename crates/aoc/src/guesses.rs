@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::custom_error::AocError;
+use crate::submit::Verdict;
+
+/// Answers the server judged, keyed like [`crate::answers::Answers`]. Unlike
+/// `answers.toml`, which `aoc verify --record` fills with whatever the
+/// solvers print, only the server puts a `correct` verdict here:
+///
+/// ```toml
+/// [[2023.05.part2]]
+/// answer = "23738617"
+/// verdict = "too-high"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Guesses(BTreeMap<String, BTreeMap<String, DayGuesses>>);
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct DayGuesses {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1: Vec<Guess>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2: Vec<Guess>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Guesses {
+    /// Guesses stay on this machine, the file is not checked in.
+    pub fn default_path() -> PathBuf {
+        crate::workspace_dir().join("guesses.toml")
+    }
+
+    /// Loads `path`, a missing file has no guesses yet.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|source| AocError::InvalidGuesses {
+                path: path.to_path_buf(),
+                source,
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let content = toml::to_string(self).expect("guesses are plain strings");
+        Ok(fs::write(path, content)?)
+    }
+
+    fn part(&self, year: u16, day: u8, part: u8) -> &[Guess] {
+        let Some(day_guesses) = self
+            .0
+            .get(&year.to_string())
+            .and_then(|days| days.get(&format!("{day:02}")))
+        else {
+            return &[];
+        };
+        match part {
+            1 => &day_guesses.part1,
+            2 => &day_guesses.part2,
+            _ => &[],
+        }
+    }
+
+    /// What the server would say to `answer`, going by earlier guesses: the
+    /// same answer gets the same verdict, anything but an accepted answer is
+    /// wrong, and a number past a guess that was too high (or too low) is too
+    /// high as well.
+    pub fn known(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<Verdict> {
+        let guesses = self.part(year, day, part);
+        if let Some(guess) = guesses.iter().find(|guess| guess.answer == answer) {
+            return Some(guess.verdict);
+        }
+        // A part has a single answer.
+        if guesses
+            .iter()
+            .any(|guess| guess.verdict == Verdict::Correct)
+        {
+            return Some(Verdict::Wrong);
+        }
+
+        let answer: i128 = answer.parse().ok()?;
+        guesses.iter().find_map(|guess| {
+            let guess_answer: i128 = guess.answer.parse().ok()?;
+            match guess.verdict {
+                Verdict::TooHigh if answer >= guess_answer => Some(Verdict::TooHigh),
+                Verdict::TooLow if answer <= guess_answer => Some(Verdict::TooLow),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: u8, answer: String, verdict: Verdict) {
+        let day_guesses = self
+            .0
+            .entry(year.to_string())
+            .or_default()
+            .entry(format!("{day:02}"))
+            .or_default();
+        let guesses = match part {
+            1 => &mut day_guesses.part1,
+            2 => &mut day_guesses.part2,
+            _ => return,
+        };
+        guesses.push(Guess { answer, verdict });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GUESSES: &str = r#"[[2023.05.part2]]
+answer = "23738617"
+verdict = "too-high"
+
+[[2023.05.part2]]
+answer = "2373861"
+verdict = "too-low"
+
+[[2023.05.part2]]
+answer = "abc"
+verdict = "wrong"
+"#;
+
+    #[test]
+    fn test_record() {
+        let mut guesses = Guesses::default();
+        guesses.record(2023, 5, 2, "23738617".to_string(), Verdict::TooHigh);
+        guesses.record(2023, 5, 2, "2373861".to_string(), Verdict::TooLow);
+        guesses.record(2023, 5, 2, "abc".to_string(), Verdict::Wrong);
+        guesses.record(2023, 5, 3, "ignored".to_string(), Verdict::Wrong);
+
+        assert_eq!(GUESSES, toml::to_string(&guesses).unwrap());
+    }
+
+    #[test]
+    fn test_known() {
+        let guesses: Guesses = toml::from_str(GUESSES).unwrap();
+        let known = |answer| guesses.known(2023, 5, 2, answer);
+
+        assert_eq!(Some(Verdict::Wrong), known("abc"));
+        assert_eq!(Some(Verdict::TooHigh), known("23738617"));
+        assert_eq!(Some(Verdict::TooHigh), known("99999999"));
+        assert_eq!(Some(Verdict::TooLow), known("12"));
+        assert_eq!(None, known("23738616"));
+        assert_eq!(None, known("def"));
+        assert_eq!(None, guesses.known(2023, 5, 1, "abc"));
+    }
+
+    #[test]
+    fn test_known_correct() {
+        let mut guesses = Guesses::default();
+        guesses.record(2023, 5, 2, "46".to_string(), Verdict::Correct);
+        assert_eq!(Some(Verdict::Correct), guesses.known(2023, 5, 2, "46"));
+        assert_eq!(Some(Verdict::Wrong), guesses.known(2023, 5, 2, "47"));
+        assert_eq!(None, guesses.known(2023, 5, 1, "47"));
+    }
+}
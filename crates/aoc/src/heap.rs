@@ -0,0 +1,319 @@
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use aoc_registry::Solver;
+
+use crate::child;
+
+/// The summary dhat prints when a profiled binary exits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    pub total_bytes: u64,
+    pub total_blocks: u64,
+    pub peak_bytes: u64,
+    pub peak_blocks: u64,
+    pub end_bytes: u64,
+}
+
+/// Reads the `dhat: Total:`, `dhat: At t-gmax:` and `dhat: At t-end:` lines.
+pub fn parse_dhat(lines: &[String]) -> Option<HeapStats> {
+    let mut total = None;
+    let mut peak = None;
+    let mut end = None;
+    for line in lines {
+        let Some((label, amounts)) = line
+            .strip_prefix("dhat: ")
+            .and_then(|line| line.split_once(':'))
+        else {
+            continue;
+        };
+        let slot = match label.trim() {
+            "Total" => &mut total,
+            "At t-gmax" => &mut peak,
+            "At t-end" => &mut end,
+            _ => continue,
+        };
+        *slot = parse_amounts(amounts);
+    }
+
+    let (total_bytes, total_blocks) = total?;
+    let (peak_bytes, peak_blocks) = peak?;
+    let (end_bytes, _) = end?;
+    Some(HeapStats {
+        total_bytes,
+        total_blocks,
+        peak_bytes,
+        peak_blocks,
+        end_bytes,
+    })
+}
+
+/// `1,234 bytes in 12 blocks`
+fn parse_amounts(amounts: &str) -> Option<(u64, u64)> {
+    let number = |number: &str| number.replace(',', "").parse().ok();
+    let (bytes, blocks) = amounts.trim().split_once(" bytes in ")?;
+    Some((number(bytes)?, number(blocks.strip_suffix(" blocks")?)?))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Stats(HeapStats),
+    Error(String),
+    Timeout,
+}
+
+/// One solver run under the dhat allocator.
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub solver: &'static Solver,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    /// Names of the columns way above the other days.
+    pub outliers: Vec<&'static str>,
+}
+
+/// Builds the `dhat-heap` binaries of `packages` up front, so that the
+/// timeout of each run doesn't include compiling.
+pub fn build(workspace: &Path, packages: &[String]) -> io::Result<bool> {
+    let mut command = cargo(workspace, "build");
+    command.args(["--profile", "dhat", "--features", "dhat-heap", "--bins"]);
+    for package in packages {
+        command.args(["--package", package]);
+    }
+    Ok(command.status()?.success())
+}
+
+/// Runs one part with the dhat allocator from `out_dir`, where dhat leaves
+/// its `dhat-heap.json`.
+pub fn profile(
+    workspace: &Path,
+    solver: &'static Solver,
+    out_dir: &Path,
+    timeout: Duration,
+) -> io::Result<Profile> {
+    let package = package(solver);
+    let finished = child::run(
+        cargo(workspace, "run")
+            .args(["--profile", "dhat", "--features", "dhat-heap"])
+            .args([
+                "--package",
+                &package,
+                "--bin",
+                &format!("part{}", solver.part),
+            ])
+            .current_dir(out_dir)
+            .env("RUST_BACKTRACE", "0"),
+        timeout,
+        8,
+        |line| !line.starts_with("note: "),
+    )?;
+
+    let outcome = match finished.status {
+        None => Outcome::Timeout,
+        Some(status) if status.success() => match parse_dhat(&finished.stderr) {
+            Some(stats) => Outcome::Stats(stats),
+            None => Outcome::Error("no dhat summary, is the dhat-heap feature wired?".to_string()),
+        },
+        Some(status) => Outcome::Error(
+            finished
+                .stderr
+                .iter()
+                .rev()
+                .find(|line| !line.starts_with("dhat: "))
+                .cloned()
+                .unwrap_or_else(|| status.to_string()),
+        ),
+    };
+
+    Ok(Profile {
+        solver,
+        outcome,
+        elapsed: finished.elapsed,
+        outliers: Vec::new(),
+    })
+}
+
+/// `y2023-day-05`, the crate `solver` comes from.
+pub fn package(solver: &Solver) -> String {
+    format!("y{}-day-{:02}", solver.year, solver.day)
+}
+
+fn cargo(workspace: &Path, subcommand: &str) -> Command {
+    let mut command = Command::new("cargo");
+    command
+        .arg(subcommand)
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(workspace.join("Cargo.toml"));
+    command
+}
+
+/// Flags the columns of each profile that are more than `factor` times the
+/// median of that column across all profiles.
+pub fn flag_outliers(profiles: &mut [Profile], factor: f64) {
+    type Column = fn(&HeapStats) -> u64;
+    let columns: [(&str, Column); 3] = [
+        ("total", |stats| stats.total_bytes),
+        ("blocks", |stats| stats.total_blocks),
+        ("peak", |stats| stats.peak_bytes),
+    ];
+
+    for (name, column) in columns {
+        let mut values: Vec<u64> = profiles
+            .iter()
+            .filter_map(|p| stats(p).map(column))
+            .collect();
+        if values.is_empty() {
+            continue;
+        }
+        values.sort_unstable();
+        let median = values[values.len() / 2] as f64;
+
+        for profile in profiles.iter_mut() {
+            if stats(profile).is_some_and(|stats| column(stats) as f64 > median * factor) {
+                profile.outliers.push(name);
+            }
+        }
+    }
+}
+
+fn stats(profile: &Profile) -> Option<&HeapStats> {
+    match &profile.outcome {
+        Outcome::Stats(stats) => Some(stats),
+        _ => None,
+    }
+}
+
+/// Column titles lined up with the rows of [`Profile`]'s `Display`.
+pub fn header() -> String {
+    format!(
+        "{:>4}  {:>3}  {:>4}  {:>10}  {:>12}  {:>10}  {:>12}  {:>10}  {}",
+        "year", "day", "part", "total", "blocks", "peak", "peak blocks", "leaked", "outliers"
+    )
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let solver = self.solver;
+        write!(
+            f,
+            "{:>4}  {:>3}  {:>4}  ",
+            solver.year, solver.day, solver.part
+        )?;
+        match &self.outcome {
+            Outcome::Stats(stats) => write!(
+                f,
+                "{:>10}  {:>12}  {:>10}  {:>12}  {:>10}  {}",
+                Bytes(stats.total_bytes),
+                stats.total_blocks,
+                Bytes(stats.peak_bytes),
+                stats.peak_blocks,
+                Bytes(stats.end_bytes),
+                self.outliers.join(", ")
+            ),
+            Outcome::Error(message) => write!(f, "error: {message}"),
+            Outcome::Timeout => write!(f, "timeout after {:.2?}", self.elapsed),
+        }
+    }
+}
+
+/// A size in bytes, with a binary unit.
+struct Bytes(u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return f.pad(&format!("{} B", self.0));
+        }
+        let mut value = self.0 as f64;
+        let mut unit = "B";
+        for next in UNITS {
+            if value < 1024.0 {
+                break;
+            }
+            value /= 1024.0;
+            unit = next;
+        }
+        f.pad(&format!("{value:.1} {unit}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(day: u8, outcome: Outcome) -> Profile {
+        Profile {
+            solver: aoc_registry::find(2023, day, 1).unwrap(),
+            outcome,
+            elapsed: Duration::from_millis(3),
+            outliers: Vec::new(),
+        }
+    }
+
+    fn stats(total_bytes: u64, total_blocks: u64, peak_bytes: u64) -> Outcome {
+        Outcome::Stats(HeapStats {
+            total_bytes,
+            total_blocks,
+            peak_bytes,
+            peak_blocks: 1,
+            end_bytes: 0,
+        })
+    }
+
+    #[test]
+    fn test_parse_dhat() {
+        let lines: Vec<String> = "107430936
+dhat: Total:     1,234,567 bytes in 12,345 blocks
+dhat: At t-gmax: 56,789 bytes in 321 blocks
+dhat: At t-end:  1,024 bytes in 1 blocks
+dhat: The data has been saved to dhat-heap.json, and is viewable with dhat/dh_view.html"
+            .lines()
+            .map(str::to_string)
+            .collect();
+
+        assert_eq!(
+            Some(HeapStats {
+                total_bytes: 1_234_567,
+                total_blocks: 12_345,
+                peak_bytes: 56_789,
+                peak_blocks: 321,
+                end_bytes: 1024,
+            }),
+            parse_dhat(&lines)
+        );
+        assert_eq!(None, parse_dhat(&lines[..2]));
+    }
+
+    #[test]
+    fn test_flag_outliers() {
+        let mut profiles = vec![
+            profile(1, stats(100, 10, 50)),
+            profile(2, stats(120, 12, 40)),
+            profile(3, stats(5_000, 11, 60)),
+            profile(4, stats(110, 900, 45)),
+            profile(5, Outcome::Timeout),
+        ];
+        flag_outliers(&mut profiles, 10.0);
+
+        let outliers: Vec<Vec<&str>> = profiles.iter().map(|p| p.outliers.clone()).collect();
+        assert_eq!(
+            vec![vec![], vec![], vec!["total"], vec!["blocks"], vec![]],
+            outliers
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let mut profile = profile(16, stats(3 * 1024 * 1024 + 1, 1234, 512));
+        profile.outliers.push("total");
+        assert_eq!(
+            "2023   16     1     3.0 MiB          1234       512 B             1         0 B  total",
+            profile.to_string()
+        );
+    }
+}
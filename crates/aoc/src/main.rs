@@ -0,0 +1,861 @@
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use aoc_input::Source;
+use aoc_registry::Solver;
+use clap::{Args, Parser, Subcommand};
+use miette::Context;
+use rayon::prelude::*;
+
+use crate::answers::Answers;
+use crate::custom_error::AocError;
+use crate::fetch::Fetched;
+use crate::guesses::Guesses;
+use crate::submit::{Submission, Verdict};
+use crate::verify::{Check, Outcome, Status};
+
+mod answers;
+mod bench;
+mod child;
+mod custom_error;
+mod fetch;
+mod guesses;
+mod heap;
+mod scaffold;
+mod submit;
+mod verify;
+mod work;
+
+/// Default of `--timeout`, in seconds. `dhat` gives twice as long, its
+/// allocator slows the solvers down.
+const TIMEOUT: u64 = 60;
+
+#[derive(Parser, Debug)]
+#[command(about = "Run Advent of Code solutions from a single binary")]
+struct Cli {
+    /// Show the solvers' debug events on stderr, `-vv` for trace events too
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run one part of a day against its puzzle input
+    Run {
+        year: u16,
+        day: u8,
+        part: u8,
+        /// Input file to use instead of the day's own, `-` reads stdin
+        #[arg(short, long)]
+        input: Option<String>,
+    },
+    /// Check every day's input files for leftovers and inconsistencies
+    Inputs,
+    /// Create a day from the template and register it with the runner
+    New {
+        year: u16,
+        day: u8,
+        /// Download the puzzle input too
+        #[arg(long)]
+        fetch: bool,
+        #[command(flatten)]
+        server: Server,
+    },
+    /// Download the puzzle input of one or more days into their crates
+    Fetch {
+        year: u16,
+        #[arg(required = true)]
+        days: Vec<u8>,
+        #[command(flatten)]
+        server: Server,
+        /// Seconds to wait between two downloads
+        #[arg(long, default_value_t = fetch::DEFAULT_INTERVAL.as_secs())]
+        interval: u64,
+    },
+    /// Send an answer to the puzzle server and record the verdict
+    Submit {
+        year: u16,
+        day: u8,
+        part: u8,
+        /// Answer to send, defaults to running the solver on the day's input
+        answer: Option<String>,
+        #[command(flatten)]
+        server: Server,
+        /// Answers file to record a correct answer in
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// File keeping the answers the server rejected
+        #[arg(long)]
+        guesses: Option<PathBuf>,
+    },
+    /// Run every registered solver and compare it with its recorded answer
+    Verify {
+        /// Only verify this year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only verify this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Seconds a solver may run before it is stopped
+        #[arg(long, default_value_t = TIMEOUT)]
+        timeout: u64,
+        /// Answers file to check against
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Record the answers of solvers that don't have one yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Rebuild and check one part against its samples and input on every change
+    Work {
+        day: u8,
+        part: u8,
+        /// Year of the day, defaults to the newest one that has it
+        #[arg(long)]
+        year: Option<u16>,
+        /// Seconds a run may take before it is stopped
+        #[arg(long, default_value_t = TIMEOUT)]
+        timeout: u64,
+        /// Answers file with the expected answer of the input
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Check once and exit instead of watching
+        #[arg(long)]
+        once: bool,
+    },
+    /// Run every solver in parallel and check it against its recorded answer
+    All {
+        /// Only run this year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only run this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Seconds a solver may run before it is stopped
+        #[arg(long, default_value_t = TIMEOUT)]
+        timeout: u64,
+        /// Answers file to check against
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Run every solver under the dhat allocator and compare their heap usage
+    Dhat {
+        /// Only profile this year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only profile this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Seconds a solver may run before it is stopped
+        #[arg(long, default_value_t = 2 * TIMEOUT)]
+        timeout: u64,
+        /// How many times the median of a column makes a part an outlier
+        #[arg(long, default_value_t = 10.0)]
+        factor: f64,
+    },
+    /// Keep a history of benchmark results and compare them
+    Bench {
+        #[command(subcommand)]
+        command: BenchCommand,
+    },
+}
+
+/// Where to reach the puzzle server and who to be there.
+#[derive(Args, Debug)]
+struct Server {
+    /// Session cookie of adventofcode.com, defaults to `AOC_SESSION`
+    #[arg(long)]
+    session: Option<String>,
+    /// Server to talk to, defaults to `AOC_BASE_URL` or adventofcode.com
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+impl Server {
+    fn client(self, interval: Duration) -> Result<fetch::Client, AocError> {
+        let session = self
+            .session
+            .or_else(|| env::var(fetch::SESSION_ENV).ok())
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(AocError::MissingSession)?;
+        let base_url = self
+            .base_url
+            .or_else(|| env::var(fetch::BASE_URL_ENV).ok())
+            .unwrap_or_else(|| fetch::DEFAULT_BASE_URL.to_string());
+        // Kept out of git next to `guesses.toml`.
+        let stamp = workspace_dir().join(".aoc-last-request");
+        Ok(fetch::Client::new(&base_url, &session, interval).with_stamp(stamp))
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum BenchCommand {
+    /// Store divan's output, read from stdin, in the benchmark history
+    Record {
+        /// Year of benches whose name doesn't include it
+        #[arg(long, default_value_t = 2023)]
+        year: u16,
+        /// Name of this machine, defaults to its hostname
+        #[arg(long)]
+        machine: Option<String>,
+        /// History file to append to
+        #[arg(long)]
+        history: Option<PathBuf>,
+    },
+    /// Flag benchmarks whose median regressed since a baseline revision
+    Compare {
+        /// Git revision (or prefix) to compare against
+        baseline: String,
+        /// Git revision to check, defaults to the latest records
+        #[arg(long)]
+        against: Option<String>,
+        /// Slowdown of the median, in percent, above which a benchmark regressed
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Name of the machine the records come from, defaults to this one
+        #[arg(long)]
+        machine: Option<String>,
+        /// History file to read
+        #[arg(long)]
+        history: Option<PathBuf>,
+    },
+}
+
+fn main() -> miette::Result<()> {
+    let cli = Cli::parse();
+    // Answers go to stdout, everything else to stderr.
+    tracing_subscriber::fmt()
+        .with_writer(io::stderr)
+        .with_max_level(match cli.verbose {
+            0 => tracing::Level::INFO,
+            1 => tracing::Level::DEBUG,
+            _ => tracing::Level::TRACE,
+        })
+        .init();
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => run(year, day, part, Source::from_arg(input.as_deref())),
+        Command::Inputs => check_inputs(),
+        Command::New {
+            year,
+            day,
+            fetch,
+            server,
+        } => new_day(year, day, fetch.then_some(server)),
+        Command::Fetch {
+            year,
+            days,
+            server,
+            interval,
+        } => fetch_inputs(year, &days, server.client(Duration::from_secs(interval))?),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            server,
+            answers,
+            guesses,
+        } => submit_answer(
+            (year, day, part),
+            answer,
+            server.client(fetch::DEFAULT_INTERVAL)?,
+            answers.unwrap_or_else(Answers::default_path),
+            guesses.unwrap_or_else(Guesses::default_path),
+        ),
+        Command::Verify {
+            year,
+            day,
+            timeout,
+            answers,
+            record,
+        } => verify(
+            year,
+            day,
+            Duration::from_secs(timeout),
+            answers.unwrap_or_else(Answers::default_path),
+            record,
+        ),
+        Command::Work {
+            day,
+            part,
+            year,
+            timeout,
+            answers,
+            once,
+        } => work(
+            year.unwrap_or_else(|| newest_year(day)),
+            day,
+            part,
+            Duration::from_secs(timeout),
+            answers.unwrap_or_else(Answers::default_path),
+            once,
+        ),
+        Command::All {
+            year,
+            day,
+            timeout,
+            answers,
+        } => run_all(
+            year,
+            day,
+            Duration::from_secs(timeout),
+            answers.unwrap_or_else(Answers::default_path),
+        ),
+        Command::Dhat {
+            year,
+            day,
+            timeout,
+            factor,
+        } => profile_heap(year, day, Duration::from_secs(timeout), factor),
+        Command::Bench {
+            command:
+                BenchCommand::Record {
+                    year,
+                    machine,
+                    history,
+                },
+        } => record_bench(
+            year,
+            machine.unwrap_or_else(hostname),
+            history.unwrap_or_else(default_bench_history),
+        ),
+        Command::Bench {
+            command:
+                BenchCommand::Compare {
+                    baseline,
+                    against,
+                    threshold,
+                    machine,
+                    history,
+                },
+        } => compare_bench(
+            &baseline,
+            against.as_deref(),
+            threshold,
+            &machine.unwrap_or_else(hostname),
+            &history.unwrap_or_else(default_bench_history),
+        ),
+    }
+}
+
+/// Root of the workspace the runner was built in.
+fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .expect("the runner lives in crates/ of the workspace")
+}
+
+#[tracing::instrument]
+fn run(year: u16, day: u8, part: u8, source: Source) -> miette::Result<()> {
+    let (answer, elapsed) = solve(year, day, part, &source)?;
+    println!("{answer}");
+    eprintln!("elapsed: {elapsed:?}");
+    Ok(())
+}
+
+/// The answer of the registered solver, and the time it took to find it.
+fn solve(year: u16, day: u8, part: u8, source: &Source) -> miette::Result<(String, Duration)> {
+    let solver = aoc_registry::find(year, day, part).map_err(AocError::from)?;
+    let input = source
+        .read(solver.day_dir(), part)
+        .map_err(AocError::from)
+        .with_context(|| format!("reading {source:?}"))?;
+
+    let start = Instant::now();
+    let answer = (solver.process)(&input)
+        .with_context(|| format!("process {year} day {day} part {part}"))?;
+    Ok((answer, start.elapsed()))
+}
+
+fn check_inputs() -> miette::Result<()> {
+    let day_dirs: BTreeSet<PathBuf> = aoc_registry::solvers()
+        .map(|solver| solver.day_dir())
+        .collect();
+
+    for day_dir in day_dirs {
+        let warnings = aoc_input::check(&day_dir).map_err(AocError::from)?;
+        for warning in warnings {
+            eprintln!("warning: {warning}");
+        }
+    }
+    Ok(())
+}
+
+/// Generates the day crate and wires it into the workspace and the registry,
+/// it is picked up by the next build of `aoc`.
+fn new_day(year: u16, day: u8, server: Option<Server>) -> miette::Result<()> {
+    // Checked before generating so that a typo doesn't cost a download.
+    let client = server
+        .map(|server| server.client(fetch::DEFAULT_INTERVAL))
+        .transpose()?;
+
+    let day_dir = aoc_registry::day_dir(year, day);
+    if day_dir.exists() {
+        // A previous run may have generated the day but failed to register
+        // it, finish the job rather than leave it half done.
+        let registered = scaffold::register(workspace_dir(), year, day)
+            .map_err(AocError::from)
+            .context("register the day")?;
+        if !registered {
+            return Err(AocError::DayExists { path: day_dir }.into());
+        }
+        eprintln!(
+            "registered the existing {} as {}",
+            day_dir.display(),
+            scaffold::package(year, day)
+        );
+    } else {
+        if !scaffold::generate(workspace_dir(), year, day).map_err(AocError::from)? {
+            return Err(AocError::GenerateFailed.into());
+        }
+        scaffold::register(workspace_dir(), year, day)
+            .map_err(AocError::from)
+            .context("register the day, run `aoc new` again once fixed")?;
+        eprintln!(
+            "created {} as {}",
+            day_dir.display(),
+            scaffold::package(year, day)
+        );
+    }
+
+    match client {
+        Some(client) => fetch_inputs(year, &[day], client),
+        None => Ok(()),
+    }
+}
+
+fn fetch_inputs(year: u16, days: &[u8], mut client: fetch::Client) -> miette::Result<()> {
+    for &day in days {
+        let day_dir = aoc_registry::day_dir(year, day);
+        if !day_dir.is_dir() {
+            return Err(AocError::MissingDay {
+                year,
+                day,
+                path: day_dir,
+            }
+            .into());
+        }
+        let path = aoc_input::canonical_path(day_dir);
+        let fetched = client
+            .fetch(year, day, &path)
+            .with_context(|| format!("fetch {year} day {day}"))?;
+        match fetched {
+            Fetched::Downloaded => eprintln!("downloaded {}", path.display()),
+            Fetched::Cached => eprintln!("{} already there", path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn submit_answer(
+    (year, day, part): (u16, u8, u8),
+    answer: Option<String>,
+    mut client: fetch::Client,
+    answers_path: PathBuf,
+    guesses_path: PathBuf,
+) -> miette::Result<()> {
+    let answer = match answer {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let (answer, _) = solve(year, day, part, &Source::Default)?;
+            eprintln!("solver answered {answer}");
+            answer
+        }
+    };
+    let mut answers = Answers::load(&answers_path)?;
+    let mut guesses = Guesses::load(&guesses_path)?;
+
+    let submission = submit::submit(
+        &mut client,
+        &mut answers,
+        &mut guesses,
+        (year, day, part),
+        &answer,
+    )
+    .with_context(|| format!("submit {year} day {day} part {part}"))?;
+    match submission {
+        Submission::Sent(Verdict::Correct) => {
+            answers.save(&answers_path)?;
+            guesses.save(&guesses_path)?
+        }
+        Submission::Sent(Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong) => {
+            guesses.save(&guesses_path)?
+        }
+        _ => {}
+    }
+
+    match submission {
+        Submission::Sent(Verdict::Correct) => {
+            println!(
+                "{answer} is correct, recorded in {}",
+                answers_path.display()
+            );
+            Ok(())
+        }
+        Submission::Known(Verdict::Correct) => {
+            println!("{answer} was already accepted");
+            Ok(())
+        }
+        Submission::Sent(Verdict::RateLimited(wait))
+        | Submission::Known(Verdict::RateLimited(wait)) => {
+            Err(AocError::RateLimited { wait }.into())
+        }
+        Submission::Sent(verdict) => Err(AocError::WrongAnswer { answer, verdict }.into()),
+        Submission::Known(verdict) => Err(AocError::KnownWrongAnswer { answer, verdict }.into()),
+    }
+}
+
+fn verify(
+    year: Option<u16>,
+    day: Option<u8>,
+    timeout: Duration,
+    answers_path: PathBuf,
+    record: bool,
+) -> miette::Result<()> {
+    let mut answers = Answers::load(&answers_path)?;
+
+    println!("{}", verify::header());
+    let mut checks = Vec::new();
+    for solver in selected(year, day) {
+        let (outcome, elapsed) = verify::run_isolated(solver, timeout).map_err(AocError::from)?;
+        let check = Check {
+            solver,
+            expected: answers
+                .get(solver.year, solver.day, solver.part)
+                .map(str::to_string),
+            outcome,
+            elapsed,
+        };
+        println!("{check}");
+        checks.push(check);
+    }
+
+    let failed = summarize(&checks);
+
+    if record {
+        let mut recorded = 0;
+        for check in checks
+            .iter()
+            .filter(|check| check.status() == Status::Missing)
+        {
+            if let Outcome::Answer(answer) = &check.outcome {
+                let solver = check.solver;
+                answers.record(solver.year, solver.day, solver.part, answer.clone());
+                recorded += 1;
+            }
+        }
+        answers.save(&answers_path)?;
+        eprintln!(
+            "recorded {recorded} answer(s) in {}",
+            answers_path.display()
+        );
+    }
+
+    if failed > 0 {
+        return Err(AocError::VerifyFailed { failed }.into());
+    }
+    Ok(())
+}
+
+/// The newest year `day` is registered in, or the newest year of all.
+fn newest_year(day: u8) -> u16 {
+    let years = || aoc_registry::solvers().map(|solver| (solver.day == day, solver.year));
+    years().max().map_or(2023, |(_, year)| year)
+}
+
+/// Runs the stages of `work::Cycle` whenever a file of the day, or of a crate
+/// of `crates/` it uses, changes.
+fn work(
+    year: u16,
+    day: u8,
+    part: u8,
+    timeout: Duration,
+    answers_path: PathBuf,
+    once: bool,
+) -> miette::Result<()> {
+    const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    let day_dir = aoc_registry::day_dir(year, day);
+    if !day_dir.is_dir() {
+        return Err(AocError::MissingDay {
+            year,
+            day,
+            path: day_dir,
+        }
+        .into());
+    }
+
+    let package = scaffold::package(year, day);
+    let watched = || -> Result<Vec<PathBuf>, AocError> {
+        let mut dirs = work::path_dependencies(workspace_dir(), &package)?;
+        dirs.insert(0, day_dir.clone());
+        Ok(dirs)
+    };
+    let mut dirs = watched()?;
+    let mut seen = None;
+    loop {
+        let snapshot = work::Snapshot::take(&dirs).map_err(AocError::from)?;
+        if seen.as_ref() == Some(&snapshot) {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+        seen = Some(snapshot);
+        // The change may have been to the dependencies of the day.
+        dirs = watched()?;
+
+        let cycle = work::Cycle {
+            workspace: workspace_dir(),
+            day_dir: &day_dir,
+            package: package.clone(),
+            part,
+            expected: Answers::load(&answers_path)?
+                .get(year, day, part)
+                .map(str::to_string),
+            timeout,
+        };
+        if !once {
+            // Like `cargo watch -c`, each cycle starts on a clean screen.
+            print!("\x1b[2J\x1b[H");
+        }
+        println!("{year} day {day} part {part}");
+        let passed = cycle
+            .run(|report| println!("{report}"))
+            .map_err(AocError::from)?;
+
+        if once {
+            return match passed {
+                true => Ok(()),
+                false => Err(AocError::WorkFailed.into()),
+            };
+        }
+        eprintln!(
+            "waiting for changes in {} and the crates it uses",
+            day_dir.display()
+        );
+    }
+}
+
+/// Same checks as `verify`, with as many solvers at a time as there are
+/// cores, and the table printed once they're all done.
+fn run_all(
+    year: Option<u16>,
+    day: Option<u8>,
+    timeout: Duration,
+    answers_path: PathBuf,
+) -> miette::Result<()> {
+    let answers = Answers::load(&answers_path)?;
+    let solvers: Vec<&'static Solver> = selected(year, day).collect();
+
+    let start = Instant::now();
+    let checks = solvers
+        .par_iter()
+        .map(|&solver| {
+            let (outcome, elapsed) = verify::run_isolated(solver, timeout)?;
+            Ok(Check {
+                solver,
+                expected: answers
+                    .get(solver.year, solver.day, solver.part)
+                    .map(str::to_string),
+                outcome,
+                elapsed,
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()
+        .map_err(AocError::from)?;
+
+    println!("{}", verify::header());
+    for check in &checks {
+        println!("{check}");
+    }
+    let failed = summarize(&checks);
+    eprintln!("wall time: {:.2?}", start.elapsed());
+
+    if failed > 0 {
+        return Err(AocError::VerifyFailed { failed }.into());
+    }
+    Ok(())
+}
+
+/// The registered solvers, narrowed down to `year` and `day` when given.
+fn selected(year: Option<u16>, day: Option<u8>) -> impl Iterator<Item = &'static Solver> {
+    aoc_registry::solvers()
+        .filter(move |solver| year.is_none_or(|year| solver.year == year))
+        .filter(move |solver| day.is_none_or(|day| solver.day == day))
+}
+
+/// Prints how many checks ended up in each status, returns the failures.
+fn summarize(checks: &[Check]) -> usize {
+    let count = |status| {
+        checks
+            .iter()
+            .filter(|check| check.status() == status)
+            .count()
+    };
+    let failed = count(Status::Fail);
+    eprintln!(
+        "{} passed, {} failed, {} missing, {} todo, {} timed out",
+        count(Status::Pass),
+        failed,
+        count(Status::Missing),
+        count(Status::Todo),
+        count(Status::Timeout)
+    );
+    failed
+}
+
+fn profile_heap(
+    year: Option<u16>,
+    day: Option<u8>,
+    timeout: Duration,
+    factor: f64,
+) -> miette::Result<()> {
+    let solvers: Vec<&'static Solver> = selected(year, day).collect();
+    let packages: Vec<String> = solvers
+        .iter()
+        .map(|solver| heap::package(solver))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    if !heap::build(workspace_dir(), &packages).map_err(AocError::from)? {
+        return Err(AocError::DhatBuildFailed.into());
+    }
+
+    let out_dir = workspace_dir().join("target").join("dhat-heap");
+    let mut profiles = Vec::new();
+    for solver in solvers {
+        let solver_dir = out_dir.join(format!("{}-part{}", heap::package(solver), solver.part));
+        fs::create_dir_all(&solver_dir).map_err(AocError::from)?;
+        let profile =
+            heap::profile(workspace_dir(), solver, &solver_dir, timeout).map_err(AocError::from)?;
+        eprintln!(
+            "profiled {} day {} part {} in {:.2?}",
+            solver.year, solver.day, solver.part, profile.elapsed
+        );
+        profiles.push(profile);
+    }
+
+    heap::flag_outliers(&mut profiles, factor);
+    println!("{}", heap::header());
+    for profile in &profiles {
+        println!("{profile}");
+    }
+    eprintln!(
+        "the dhat-heap.json of each part is in {}, open them with dh_view.html",
+        out_dir.display()
+    );
+    Ok(())
+}
+
+fn default_bench_history() -> PathBuf {
+    workspace_dir().join("benchmarks.jsonl")
+}
+
+fn hostname() -> String {
+    process::Command::new("hostname")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|hostname| hostname.trim().to_string())
+        .filter(|hostname| !hostname.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Short hash of `HEAD`, suffixed with `-dirty` when the tree has changes.
+fn git_rev() -> String {
+    let git = |args: &[&str]| {
+        process::Command::new("git")
+            .args(args)
+            .current_dir(workspace_dir())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(rev) => {
+            let dirty = git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty());
+            format!("{}{}", rev.trim(), if dirty { "-dirty" } else { "" })
+        }
+        None => "unknown".to_string(),
+    }
+}
+
+fn record_bench(year: u16, machine: String, history: PathBuf) -> miette::Result<()> {
+    let mut output = String::new();
+    io::stdin()
+        .read_to_string(&mut output)
+        .map_err(AocError::from)
+        .context("read divan output from stdin")?;
+    // Pass the table through, this sits at the end of a `cargo bench` pipe.
+    print!("{output}");
+
+    let rev = git_rev();
+    let recorded_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let records: Vec<bench::Record> = bench::parse_divan(&output, year)
+        .into_iter()
+        .map(|measurement| bench::Record {
+            measurement,
+            machine: machine.clone(),
+            rev: rev.clone(),
+            recorded_at,
+        })
+        .collect();
+
+    bench::append(&history, &records)?;
+    eprintln!(
+        "recorded {} benchmark(s) for {machine} at {rev} in {}",
+        records.len(),
+        history.display()
+    );
+    Ok(())
+}
+
+fn compare_bench(
+    baseline: &str,
+    against: Option<&str>,
+    threshold: f64,
+    machine: &str,
+    history: &Path,
+) -> miette::Result<()> {
+    let records = bench::load(history)?;
+    if !records
+        .iter()
+        .any(|record| record.machine == machine && record.rev.starts_with(baseline))
+    {
+        return Err(AocError::UnknownBaseline {
+            rev: baseline.to_string(),
+            machine: machine.to_string(),
+        }
+        .into());
+    }
+
+    let comparisons = bench::compare(&records, machine, baseline, against, threshold);
+    println!("{}", bench::header());
+    for comparison in &comparisons {
+        println!("{comparison}");
+    }
+
+    let regressed = comparisons.iter().filter(|c| c.regressed).count();
+    if regressed > 0 {
+        return Err(AocError::BenchRegressed { regressed }.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,222 @@
+use std::cmp::Ordering;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+/// `y2024-day-05`, the package of a day crate.
+pub fn package(year: u16, day: u8) -> String {
+    format!("y{year}-day-{day:02}")
+}
+
+/// Renders `daily-template` into `<year>/rust/day-NN` with cargo-generate.
+pub fn generate(workspace: &Path, year: u16, day: u8) -> io::Result<bool> {
+    let destination = workspace.join(year.to_string()).join("rust");
+    fs::create_dir_all(&destination)?;
+    let status = Command::new("cargo")
+        .arg("generate")
+        .arg("--path")
+        .arg(workspace.join("daily-template"))
+        .arg("--destination")
+        .arg(destination)
+        .args(["--name", &format!("day-{day:02}")])
+        .args(["--define", &format!("year={year}")])
+        .args(["--vcs", "none", "--silent"])
+        .status()?;
+    Ok(status.success())
+}
+
+/// Adds the day to the workspace members, the registry's dependencies and
+/// its solver list. Files that already have it are left alone, `false` when
+/// they all did.
+pub fn register(workspace: &Path, year: u16, day: u8) -> io::Result<bool> {
+    type Edit = fn(&str, u16, u8) -> Option<String>;
+    let registry = workspace.join("crates").join("aoc-registry");
+    let edits: [(&Path, Edit); 3] = [
+        (&workspace.join("Cargo.toml"), add_member),
+        (&registry.join("Cargo.toml"), add_dependency),
+        (&registry.join("src").join("lib.rs"), add_solver),
+    ];
+    let mut edited = false;
+    for (path, edit) in edits {
+        if let Some(content) = edit(&fs::read_to_string(path)?, year, day) {
+            fs::write(path, content)?;
+            edited = true;
+        }
+    }
+    Ok(edited)
+}
+
+/// Adds `"<year>/rust/day-*"` to the members of the root manifest, after the
+/// other years.
+fn add_member(manifest: &str, year: u16, _day: u8) -> Option<String> {
+    let member = format!("\"{year}/rust/day-*\"");
+    insert_sorted(manifest, |line| {
+        let line = line.trim().trim_end_matches(',');
+        line.ends_with("/rust/day-*\"").then(|| line.cmp(&member))
+    })
+    .map(|index| insert_line(manifest, index, &format!("    {member},")))
+}
+
+/// Adds the path dependency on the day crate to the registry's manifest.
+fn add_dependency(manifest: &str, year: u16, day: u8) -> Option<String> {
+    let package = package(year, day);
+    insert_sorted(manifest, |line| {
+        let (name, _) = line.split_once(" = ")?;
+        is_day_package(name).then(|| name.cmp(&package))
+    })
+    .map(|index| {
+        let dependency = format!("{package} = {{ path = \"../../{year}/rust/day-{day:02}\" }}");
+        insert_line(manifest, index, &dependency)
+    })
+}
+
+/// Adds `solution!(year, day, yYYYY_day_NN::DayNN)` to the registry's
+/// `SOLVERS`, in year and day order.
+fn add_solver(source: &str, year: u16, day: u8) -> Option<String> {
+    insert_sorted(source, |line| {
+        let (_, args) = line.trim().split_once("!(")?;
+        let mut args = args.split(", ");
+        let registered: (u16, u8) = (args.next()?.parse().ok()?, args.next()?.parse().ok()?);
+        Some(registered.cmp(&(year, day)))
+    })
+    .map(|index| {
+        let krate = package(year, day).replace('-', "_");
+        let solver = format!("    solution!({year}, {day}, {krate}::Day{day:02}),");
+        insert_line(source, index, &solver)
+    })
+}
+
+fn is_day_package(name: &str) -> bool {
+    name.strip_prefix('y')
+        .and_then(|name| name.split_once("-day-"))
+        .is_some_and(|(year, day)| {
+            [year, day]
+                .iter()
+                .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+        })
+}
+
+/// Index of the line to insert before so that the lines `compare` knows
+/// about stay sorted, `None` when one of them is equal already. `compare`
+/// orders an existing line against the new one.
+fn insert_sorted(content: &str, compare: impl Fn(&str) -> Option<Ordering>) -> Option<usize> {
+    let mut index = None;
+    for (number, line) in content.lines().enumerate() {
+        match compare(line) {
+            Some(Ordering::Equal) => return None,
+            Some(Ordering::Less) => index = Some(number + 1),
+            Some(Ordering::Greater) => return Some(index.unwrap_or(number)),
+            None => {}
+        }
+    }
+    index
+}
+
+fn insert_line(content: &str, index: usize, line: &str) -> String {
+    let mut lines: Vec<&str> = content.lines().collect();
+    lines.insert(index, line);
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_member() {
+        let manifest = "members = [\n    \"crates/*\",\n    \"2023/rust/day-*\",\n]\n";
+        assert_eq!(
+            Some("members = [\n    \"crates/*\",\n    \"2023/rust/day-*\",\n    \"2024/rust/day-*\",\n]\n".to_string()),
+            add_member(manifest, 2024, 1)
+        );
+        assert_eq!(None, add_member(manifest, 2023, 20));
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = "[dependencies]
+miette = { workspace = true }
+y2023-day-01 = { path = \"../../2023/rust/day-01\" }
+y2024-day-01 = { path = \"../../2024/rust/day-01\" }
+";
+        assert_eq!(
+            Some(
+                "[dependencies]
+miette = { workspace = true }
+y2023-day-01 = { path = \"../../2023/rust/day-01\" }
+y2023-day-02 = { path = \"../../2023/rust/day-02\" }
+y2024-day-01 = { path = \"../../2024/rust/day-01\" }
+"
+                .to_string()
+            ),
+            add_dependency(manifest, 2023, 2)
+        );
+        assert_eq!(None, add_dependency(manifest, 2024, 1));
+    }
+
+    #[test]
+    fn test_add_solver() {
+        let source = "static SOLVERS: &[[Solver; 2]] = &[
+    day!(2023, 1, y2023_day_01),
+    solution!(2023, 19, y2023_day_19::Day19),
+];
+";
+        assert_eq!(
+            Some(
+                "static SOLVERS: &[[Solver; 2]] = &[
+    day!(2023, 1, y2023_day_01),
+    solution!(2023, 19, y2023_day_19::Day19),
+    solution!(2024, 3, y2024_day_03::Day03),
+];
+"
+                .to_string()
+            ),
+            add_solver(source, 2024, 3)
+        );
+        assert_eq!(
+            Some(
+                "static SOLVERS: &[[Solver; 2]] = &[
+    day!(2023, 1, y2023_day_01),
+    solution!(2023, 2, y2023_day_02::Day02),
+    solution!(2023, 19, y2023_day_19::Day19),
+];
+"
+                .to_string()
+            ),
+            add_solver(source, 2023, 2)
+        );
+        assert_eq!(None, add_solver(source, 2023, 19));
+    }
+
+    #[test]
+    fn test_register() -> io::Result<()> {
+        let workspace = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let registry = workspace.join("crates").join("aoc-registry");
+        fs::create_dir_all(registry.join("src"))?;
+        fs::write(
+            workspace.join("Cargo.toml"),
+            "members = [\n    \"crates/*\",\n    \"2023/rust/day-*\",\n]\n",
+        )?;
+        fs::write(
+            registry.join("Cargo.toml"),
+            "[dependencies]\ny2023-day-01 = { path = \"../../2023/rust/day-01\" }\n",
+        )?;
+        fs::write(
+            registry.join("src").join("lib.rs"),
+            "static SOLVERS: &[[Solver; 2]] = &[\n    day!(2023, 1, y2023_day_01),\n];\n",
+        )?;
+
+        let first = register(&workspace, 2024, 2);
+        let second = register(&workspace, 2024, 2);
+        let manifest = fs::read_to_string(registry.join("Cargo.toml"));
+        fs::remove_dir_all(&workspace)?;
+
+        assert!(first?);
+        assert!(!second?);
+        assert!(manifest?.contains("y2024-day-02 = { path = \"../../2024/rust/day-02\" }"));
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+use std::fmt;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answers::Answers;
+use crate::custom_error::AocError;
+use crate::fetch::Client;
+use crate::guesses::Guesses;
+
+/// How the server judged an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was given too recently, nothing was checked.
+    RateLimited(Duration),
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the server responds with.
+    pub fn parse(page: &str) -> Result<Self, AocError> {
+        let message = article(page);
+        let verdict = if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if message.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::RateLimited(parse_wait(&message).unwrap_or_default())
+        } else {
+            return Err(AocError::UnexpectedResponse { message });
+        };
+        Ok(verdict)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => write!(f, "rate limited for {}s", wait.as_secs()),
+        }
+    }
+}
+
+/// Text of the `<article>` the server puts its message in, without markup.
+fn article(page: &str) -> String {
+    let start = page
+        .find("<article>")
+        .map_or(0, |start| start + "<article>".len());
+    let end = page[start..]
+        .find("</article>")
+        .map_or(page.len(), |end| start + end);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in page[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `You have 1m 23s left to wait.`
+fn parse_wait(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .map(|amount| {
+            let unit = amount.chars().last()?;
+            let value: u64 = amount[..amount.len() - unit.len_utf8()].parse().ok()?;
+            match unit {
+                'h' => Some(value * 3600),
+                'm' => Some(value * 60),
+                's' => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Submission {
+    /// Sent to the server, which gave this verdict.
+    Sent(Verdict),
+    /// Not sent, the verdict follows from earlier guesses.
+    Known(Verdict),
+}
+
+/// Submits `answer` unless the server already judged it, then records the
+/// verdict in `guesses` and a correct answer in `answers` as well. What
+/// `answers` holds is not proof the server accepted it, so it is never
+/// checked.
+pub fn submit(
+    client: &mut Client,
+    answers: &mut Answers,
+    guesses: &mut Guesses,
+    (year, day, part): (u16, u8, u8),
+    answer: &str,
+) -> Result<Submission, AocError> {
+    if let Some(verdict) = guesses.known(year, day, part, answer) {
+        return Ok(Submission::Known(verdict));
+    }
+
+    let verdict = Verdict::parse(&client.submit(year, day, part, answer)?)?;
+    match verdict {
+        Verdict::Correct => {
+            answers.record(year, day, part, answer.to_string());
+            guesses.record(year, day, part, answer.to_string(), verdict);
+        }
+        Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => {
+            guesses.record(year, day, part, answer.to_string(), verdict)
+        }
+        Verdict::RateLimited(_) => {}
+    }
+    Ok(Submission::Sent(verdict))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::serve;
+
+    fn page(message: &str) -> String {
+        format!("<html><main>\n<article><p>{message}</p></article>\n</main></html>")
+    }
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        assert_eq!(
+            Verdict::Correct,
+            Verdict::parse(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."))?
+        );
+        assert_eq!(
+            Verdict::TooHigh,
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too high."
+            ))?
+        );
+        assert_eq!(
+            Verdict::TooLow,
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too low."
+            ))?
+        );
+        assert_eq!(
+            Verdict::Wrong,
+            Verdict::parse(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data."))?
+        );
+        assert_eq!(
+            Verdict::RateLimited(Duration::from_secs(83)),
+            Verdict::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait."))?
+        );
+        assert!(matches!(
+            Verdict::parse(&page("You don't seem to be solving the right level.  Did you already complete it?")),
+            Err(AocError::UnexpectedResponse { message }) if message.starts_with("You don't seem")
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_submit() -> miette::Result<()> {
+        let (base_url, requests) = serve(
+            "200 OK",
+            &page("That's not the right answer; your answer is too high."),
+        );
+        let mut client = Client::new(&base_url, "cafe", Duration::ZERO);
+        let mut answers = Answers::default();
+        let mut guesses = Guesses::default();
+        let mut send = |answer| {
+            submit(
+                &mut client,
+                &mut answers,
+                &mut guesses,
+                (2023, 5, 2),
+                answer,
+            )
+        };
+
+        assert_eq!(Submission::Sent(Verdict::TooHigh), send("100")?);
+        requests.recv().unwrap();
+        assert_eq!(Submission::Known(Verdict::TooHigh), send("100")?);
+        assert_eq!(Submission::Known(Verdict::TooHigh), send("101")?);
+        assert!(requests.try_recv().is_err());
+        assert_eq!(Some(Verdict::TooHigh), guesses.known(2023, 5, 2, "100"));
+
+        // A recorded answer the server never saw is still sent.
+        answers.record(2023, 5, 2, "46".to_string());
+        let (base_url, requests) = serve("200 OK", &page("That's the right answer!"));
+        let mut client = Client::new(&base_url, "cafe", Duration::ZERO);
+        let mut send = |answer| {
+            submit(
+                &mut client,
+                &mut answers,
+                &mut guesses,
+                (2023, 5, 2),
+                answer,
+            )
+        };
+        assert_eq!(Submission::Sent(Verdict::Correct), send("42")?);
+        requests.recv().unwrap();
+        assert_eq!(Submission::Known(Verdict::Correct), send("42")?);
+        assert_eq!(Submission::Known(Verdict::Wrong), send("46")?);
+        assert!(requests.try_recv().is_err());
+        assert_eq!(Some("42"), answers.get(2023, 5, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_submit_correct() -> miette::Result<()> {
+        let (base_url, _requests) = serve("200 OK", &page("That's the right answer!"));
+        let mut client = Client::new(&base_url, "cafe", Duration::ZERO);
+        let mut answers = Answers::default();
+        let mut guesses = Guesses::default();
+
+        assert_eq!(
+            Submission::Sent(Verdict::Correct),
+            submit(&mut client, &mut answers, &mut guesses, (2023, 5, 2), "46")?
+        );
+        assert_eq!(Some("46"), answers.get(2023, 5, 2));
+        assert_eq!(Some(Verdict::Correct), guesses.known(2023, 5, 2, "46"));
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+use std::env;
+use std::fmt;
+use std::io;
+use std::process::Command;
+use std::time::Duration;
+
+use aoc_registry::{Solver, TODO_PANIC};
+
+use crate::child;
+
+/// Column titles lined up with the rows of [`Check`]'s `Display`.
+pub fn header() -> String {
+    format!(
+        "{:>4}  {:>3}  {:>4}  {:<7}  {:>10}  {:<20}  {}",
+        "year", "day", "part", "status", "time", "expected", "actual"
+    )
+}
+
+/// What a solver produced when run against its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(String),
+    Error(String),
+    /// The solver hit a `todo!`.
+    Unimplemented,
+    Timeout,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Todo,
+    Timeout,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::Todo => "todo",
+            Status::Timeout => "timeout",
+        };
+        f.pad(status)
+    }
+}
+
+/// One solver checked against its recorded answer.
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub solver: &'static Solver,
+    pub expected: Option<String>,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+impl Check {
+    /// A timeout is not a mismatch, slow solvers can be given more time.
+    /// Neither is a part that isn't written yet.
+    pub fn status(&self) -> Status {
+        match (&self.expected, &self.outcome) {
+            // A solver that gives no answer fails, recorded answer or not.
+            (_, Outcome::Unimplemented) => Status::Todo,
+            (_, Outcome::Timeout) => Status::Timeout,
+            (_, Outcome::Error(_)) => Status::Fail,
+            (None, Outcome::Answer(_)) => Status::Missing,
+            (Some(expected), Outcome::Answer(answer)) if answer == expected => Status::Pass,
+            (Some(_), Outcome::Answer(_)) => Status::Fail,
+        }
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let actual = match &self.outcome {
+            Outcome::Answer(answer) => answer.as_str(),
+            Outcome::Error(message) => message.as_str(),
+            Outcome::Unimplemented | Outcome::Timeout => "-",
+        };
+        write!(
+            f,
+            "{:>4}  {:>3}  {:>4}  {:<7}  {:>10}  {:<20}  {}",
+            self.solver.year,
+            self.solver.day,
+            self.solver.part,
+            self.status(),
+            format!("{:.2?}", self.elapsed),
+            self.expected.as_deref().unwrap_or("-"),
+            actual
+        )
+    }
+}
+
+/// Runs `solver` through `aoc run` in a child process, so that it can be
+/// killed once `timeout` is reached.
+pub fn run_isolated(solver: &Solver, timeout: Duration) -> io::Result<(Outcome, Duration)> {
+    let finished = child::run(
+        Command::new(env::current_exe()?)
+            .arg("run")
+            .arg(solver.year.to_string())
+            .arg(solver.day.to_string())
+            .arg(solver.part.to_string())
+            .env("RUST_BACKTRACE", "0"),
+        timeout,
+        1,
+        |line| !is_noise(line),
+    )?;
+
+    let last = |lines: Vec<String>| lines.into_iter().last();
+    let outcome = match finished.status {
+        None => Outcome::Timeout,
+        Some(status) if status.success() => {
+            Outcome::Answer(last(finished.stdout).unwrap_or_default())
+        }
+        Some(status) => match last(finished.stderr) {
+            Some(message) if message.starts_with(TODO_PANIC) => Outcome::Unimplemented,
+            message => Outcome::Error(message.unwrap_or_else(|| status.to_string())),
+        },
+    };
+
+    Ok((outcome, finished.elapsed))
+}
+
+/// Lines the runner or the panic handler print around the actual output.
+fn is_noise(line: &str) -> bool {
+    line.starts_with("elapsed: ") || line.starts_with("note: ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(expected: Option<&str>, outcome: Outcome) -> Check {
+        Check {
+            solver: aoc_registry::find(2023, 6, 1).unwrap(),
+            expected: expected.map(str::to_string),
+            outcome,
+            elapsed: Duration::from_millis(3),
+        }
+    }
+
+    #[test]
+    fn test_status() {
+        let answer = || Outcome::Answer("288".to_string());
+
+        assert_eq!(Status::Pass, check(Some("288"), answer()).status());
+        assert_eq!(Status::Fail, check(Some("71503"), answer()).status());
+        assert_eq!(
+            Status::Fail,
+            check(Some("288"), Outcome::Error("boom".to_string())).status()
+        );
+        assert_eq!(
+            Status::Timeout,
+            check(Some("288"), Outcome::Timeout).status()
+        );
+        assert_eq!(Status::Missing, check(None, answer()).status());
+        assert_eq!(Status::Timeout, check(None, Outcome::Timeout).status());
+        assert_eq!(
+            Status::Fail,
+            check(None, Outcome::Error("boom".to_string())).status()
+        );
+        assert_eq!(Status::Todo, check(None, Outcome::Unimplemented).status());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "2023    6     1  FAIL         3.00ms  71503                 288",
+            check(Some("71503"), Outcome::Answer("288".to_string())).to_string()
+        );
+    }
+}
@@ -0,0 +1,402 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant, SystemTime};
+
+use aoc_input::Fixture;
+
+use crate::child;
+
+/// Modification times of every file of a day crate and the crates it depends
+/// on, to notice edits without a file system watcher.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, SystemTime>);
+
+impl Snapshot {
+    pub fn take(dirs: &[PathBuf]) -> io::Result<Self> {
+        let mut snapshot = Self::default();
+        for dir in dirs {
+            snapshot.walk(dir)?;
+        }
+        Ok(snapshot)
+    }
+
+    fn walk(&mut self, dir: &Path) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            if entry.file_type()?.is_dir() {
+                if entry.file_name() != "target" {
+                    self.walk(&path)?;
+                }
+            } else {
+                self.0.insert(path, entry.metadata()?.modified()?);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One step of a work cycle, each one only runs if the previous passed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stage {
+    Build,
+    /// A sample from `fixtures/`, by directory name.
+    Sample(String),
+    Input,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Build => f.pad("build"),
+            Stage::Sample(name) => f.pad(name),
+            Stage::Input => f.pad("input"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    /// The answer of the real input when there's no recorded one yet.
+    Answer(String),
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    Error(String),
+    Timeout,
+}
+
+impl Verdict {
+    pub fn passed(&self) -> bool {
+        matches!(self, Verdict::Pass | Verdict::Answer(_))
+    }
+
+    fn of(expected: Option<&str>, actual: String) -> Self {
+        match expected {
+            None => Verdict::Answer(actual),
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.to_string(),
+                actual,
+            },
+        }
+    }
+}
+
+/// How a stage went, printed as one line, plus a diff on a mismatch.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub stage: Stage,
+    pub verdict: Verdict,
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elapsed = format!("{:.2?}", self.elapsed);
+        match &self.verdict {
+            Verdict::Pass => write!(f, "{:<12}  pass     {elapsed:>10}", self.stage),
+            Verdict::Answer(answer) => write!(
+                f,
+                "{:<12}  answer   {elapsed:>10}  {answer} (not recorded yet)",
+                self.stage
+            ),
+            Verdict::Mismatch { expected, actual } => {
+                writeln!(f, "{:<12}  FAIL     {elapsed:>10}", self.stage)?;
+                write!(f, "{}", diff(expected, actual))
+            }
+            Verdict::Error(message) => {
+                write!(f, "{:<12}  ERROR    {elapsed:>10}  {message}", self.stage)
+            }
+            Verdict::Timeout => write!(f, "{:<12}  timeout  {elapsed:>10}", self.stage),
+        }
+    }
+}
+
+/// Line by line, `-` for what was expected and `+` for what came out.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut diff = String::new();
+    for line in 0..expected.len().max(actual.len()) {
+        match (expected.get(line), actual.get(line)) {
+            (Some(expected), Some(actual)) if expected == actual => {
+                diff.push_str(&format!("    {expected}\n"));
+            }
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    diff.push_str(&format!("  - {expected}\n"));
+                }
+                if let Some(actual) = actual {
+                    diff.push_str(&format!("  + {actual}\n"));
+                }
+            }
+        }
+    }
+    diff.trim_end().to_string()
+}
+
+/// Where the stages of one day and part run.
+pub struct Cycle<'a> {
+    pub workspace: &'a Path,
+    pub day_dir: &'a Path,
+    pub package: String,
+    pub part: u8,
+    /// The recorded answer of the real input.
+    pub expected: Option<String>,
+    pub timeout: Duration,
+}
+
+impl Cycle<'_> {
+    /// Runs the stages until one fails, `show` sees each of them as soon
+    /// as it's done.
+    pub fn run(&self, mut show: impl FnMut(&Report)) -> io::Result<bool> {
+        let start = Instant::now();
+        let Some(binary) = self.build()? else {
+            show(&Report {
+                stage: Stage::Build,
+                verdict: Verdict::Error("see cargo's output above".to_string()),
+                elapsed: start.elapsed(),
+            });
+            return Ok(false);
+        };
+        show(&Report {
+            stage: Stage::Build,
+            verdict: Verdict::Pass,
+            elapsed: start.elapsed(),
+        });
+
+        for answer in Fixture::answers(self.day_dir, self.part)? {
+            let name = answer
+                .parent()
+                .and_then(Path::file_name)
+                .map_or_else(String::new, |name| name.to_string_lossy().to_string());
+            let fixture = Fixture::load(&answer)?;
+            let report = self.solve(
+                Stage::Sample(name),
+                &binary,
+                Some(&answer.with_file_name("input.txt")),
+                Some(&fixture.expected),
+            )?;
+            show(&report);
+            if !report.verdict.passed() {
+                return Ok(false);
+            }
+        }
+
+        let report = self.solve(Stage::Input, &binary, None, self.expected.as_deref())?;
+        show(&report);
+        Ok(report.verdict.passed())
+    }
+
+    /// Builds the `partN` binary and returns its path, `None` when it
+    /// doesn't compile. Cargo's diagnostics go straight to the terminal.
+    fn build(&self) -> io::Result<Option<PathBuf>> {
+        let bin = format!("part{}", self.part);
+        let output = Command::new("cargo")
+            .arg("build")
+            .arg("--release")
+            .arg("--manifest-path")
+            .arg(self.workspace.join("Cargo.toml"))
+            .args(["--package", &self.package, "--bin", &bin])
+            .args(["--message-format", "json-diagnostic-rendered-ansi"])
+            .stdin(Stdio::null())
+            .stderr(Stdio::inherit())
+            .output()?;
+
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let Ok(message) = serde_json::from_str::<serde_json::Value>(line) else {
+                continue;
+            };
+            if let Some(rendered) = message["message"]["rendered"].as_str() {
+                eprint!("{rendered}");
+            }
+        }
+        if !output.status.success() {
+            return Ok(None);
+        }
+        Ok(executable(&String::from_utf8_lossy(&output.stdout), &bin))
+    }
+
+    fn solve(
+        &self,
+        stage: Stage,
+        binary: &Path,
+        input: Option<&Path>,
+        expected: Option<&str>,
+    ) -> io::Result<Report> {
+        let mut command = Command::new(binary);
+        command.current_dir(self.day_dir).env("RUST_BACKTRACE", "0");
+        if let Some(input) = input {
+            command.arg(input);
+        }
+        let finished = child::run(&mut command, self.timeout, 100, |line| {
+            !line.starts_with("note: ")
+        })?;
+
+        let verdict = match finished.status {
+            None => Verdict::Timeout,
+            Some(status) if status.success() => Verdict::of(expected, finished.stdout.join("\n")),
+            Some(status) => Verdict::Error(
+                finished
+                    .stderr
+                    .last()
+                    .cloned()
+                    .unwrap_or_else(|| status.to_string()),
+            ),
+        };
+        Ok(Report {
+            stage,
+            verdict,
+            elapsed: finished.elapsed,
+        })
+    }
+}
+
+/// Path of the `bin` executable in cargo's `--message-format json` output.
+/// Directories of the crates of the workspace `package` depends on by path,
+/// directly or not. A manifest cargo can't read has none.
+pub fn path_dependencies(workspace: &Path, package: &str) -> io::Result<Vec<PathBuf>> {
+    let output = Command::new("cargo")
+        .args(["metadata", "--format-version", "1", "--no-deps"])
+        .arg("--manifest-path")
+        .arg(workspace.join("Cargo.toml"))
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()?;
+    Ok(dependency_dirs(
+        &String::from_utf8_lossy(&output.stdout),
+        package,
+    ))
+}
+
+/// What [`path_dependencies`] finds in the output of `cargo metadata`.
+fn dependency_dirs(metadata: &str, package: &str) -> Vec<PathBuf> {
+    let Ok(metadata) = serde_json::from_str::<serde_json::Value>(metadata) else {
+        return Vec::new();
+    };
+    let packages = metadata["packages"]
+        .as_array()
+        .map_or(&[][..], Vec::as_slice);
+    let mut dirs = BTreeSet::new();
+    let mut seen = BTreeSet::new();
+    let mut pending = vec![package.to_string()];
+    while let Some(name) = pending.pop() {
+        if !seen.insert(name.clone()) {
+            continue;
+        }
+        let Some(package) = packages
+            .iter()
+            .find(|package| package["name"] == name.as_str())
+        else {
+            continue;
+        };
+        for dependency in package["dependencies"].as_array().into_iter().flatten() {
+            if let (Some(name), Some(path)) =
+                (dependency["name"].as_str(), dependency["path"].as_str())
+            {
+                dirs.insert(PathBuf::from(path));
+                pending.push(name.to_string());
+            }
+        }
+    }
+    dirs.into_iter().collect()
+}
+
+fn executable(messages: &str, bin: &str) -> Option<PathBuf> {
+    messages
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter(|message| message["reason"] == "compiler-artifact")
+        .filter(|message| message["target"]["name"] == bin)
+        .find_map(|message| message["executable"].as_str().map(PathBuf::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        assert_eq!("  - 46\n  + 45", diff("46", "45"));
+        assert_eq!("    a\n  - b\n  + c\n  + d", diff("a\nb", "a\nc\nd"));
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(Verdict::Pass, Verdict::of(Some("46"), "46".to_string()));
+        assert_eq!(
+            Verdict::Mismatch {
+                expected: "46".to_string(),
+                actual: "45".to_string()
+            },
+            Verdict::of(Some("46"), "45".to_string())
+        );
+        assert!(Verdict::of(None, "45".to_string()).passed());
+    }
+
+    #[test]
+    fn test_executable() {
+        let messages = r#"{"reason":"compiler-artifact","target":{"name":"y2023_day_05"},"executable":null}
+{"reason":"compiler-artifact","target":{"name":"part2"},"executable":"/ws/target/release/part2"}
+{"reason":"build-finished","success":true}"#;
+        assert_eq!(
+            Some(PathBuf::from("/ws/target/release/part2")),
+            executable(messages, "part2")
+        );
+        assert_eq!(None, executable(messages, "part1"));
+    }
+
+    #[test]
+    fn test_dependency_dirs() {
+        let metadata = r#"{"packages": [
+            {"name": "y2023-day-03", "dependencies": [
+                {"name": "aoc-grid", "path": "/ws/crates/aoc-grid"},
+                {"name": "aoc-solution", "path": "/ws/crates/aoc-solution"},
+                {"name": "nom", "path": null}
+            ]},
+            {"name": "aoc-solution", "dependencies": [
+                {"name": "aoc-input", "path": "/ws/crates/aoc-input"}
+            ]},
+            {"name": "aoc-input", "dependencies": []},
+            {"name": "y2023-day-04", "dependencies": [
+                {"name": "aoc-parse", "path": "/ws/crates/aoc-parse"}
+            ]}
+        ]}"#;
+        assert_eq!(
+            vec![
+                PathBuf::from("/ws/crates/aoc-grid"),
+                PathBuf::from("/ws/crates/aoc-input"),
+                PathBuf::from("/ws/crates/aoc-solution"),
+            ],
+            dependency_dirs(metadata, "y2023-day-03")
+        );
+        assert_eq!(Vec::<PathBuf>::new(), dependency_dirs("", "y2023-day-03"));
+    }
+
+    #[test]
+    fn test_snapshot() -> io::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-work-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src"))?;
+        fs::write(dir.join("src/lib.rs"), "")?;
+        let dirs = [dir.clone()];
+        let before = Snapshot::take(&dirs)?;
+        let unchanged = Snapshot::take(&dirs)?;
+        fs::write(dir.join("input.txt"), "1\n")?;
+        let changed = Snapshot::take(&dirs)?;
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(before, unchanged);
+        assert_ne!(before, changed);
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+// Entry point of the solver worker, see `src/worker.rs`. The bundle is an ES
+// module, loaded with a dynamic import so that the worker doesn't need to be
+// a module worker.
+import("/pkg/www.js").then(async (www) => {
+  await www.default();
+  www.solver();
+});
@@ -0,0 +1,286 @@
+use std::collections::BTreeSet;
+
+use aoc_registry::Solver;
+use leptos::prelude::*;
+use leptos_meta::{provide_meta_context, MetaTags, Stylesheet, Title};
+
+use crate::worker::Run;
+
+/// The answer to one part, computed in the browser.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Result<String, String>,
+    /// `None` when the part didn't finish.
+    pub elapsed_ms: Option<f64>,
+}
+
+/// The registered parts of `day`.
+pub fn solvers(year: u16, day: u8) -> impl Iterator<Item = &'static Solver> {
+    aoc_registry::solvers().filter(move |solver| solver.year == year && solver.day == day)
+}
+
+/// Runs `solver` on `input`, timed with `now`, a clock in milliseconds.
+pub fn solve(solver: &Solver, input: &str, now: impl Fn() -> f64) -> PartResult {
+    let start = now();
+    let answer = (solver.process)(input).map_err(|error| {
+        error
+            .chain()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(": ")
+    });
+    PartResult {
+        part: solver.part,
+        answer,
+        elapsed_ms: Some(now() - start),
+    }
+}
+
+/// Gives the `parts` that have no result yet `error` as their answer.
+fn fail_missing(results: &mut Vec<PartResult>, parts: &[u8], error: &str) {
+    for &part in parts {
+        if !results.iter().any(|result| result.part == part) {
+            results.push(PartResult {
+                part,
+                answer: Err(error.to_string()),
+                elapsed_ms: None,
+            });
+        }
+    }
+    results.sort_by_key(|result| result.part);
+}
+
+fn days() -> Vec<(u16, u8)> {
+    aoc_registry::solvers()
+        .map(|solver| (solver.year, solver.day))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// `2023-5`, the value of an option of the day picker.
+fn parse_day(value: &str) -> Option<(u16, u8)> {
+    let (year, day) = value.split_once('-')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+pub fn shell(options: LeptosOptions) -> impl IntoView {
+    view! {
+        <!DOCTYPE html>
+        <html lang="en">
+            <head>
+                <meta charset="utf-8" />
+                <meta name="viewport" content="width=device-width, initial-scale=1" />
+                <AutoReload options=options.clone() />
+                <HydrationScripts options />
+                <MetaTags />
+            </head>
+            <body>
+                <App />
+            </body>
+        </html>
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    provide_meta_context();
+
+    let days = days();
+    let (selected, set_selected) = signal(days.first().copied().unwrap_or((2023, 1)));
+    let (input, set_input) = signal(String::new());
+    let (results, set_results) = signal(Vec::<PartResult>::new());
+
+    // The workers of the current run, terminated when it's replaced.
+    let workers = StoredValue::new_local(None::<Run>);
+    let (running, set_running) = signal(false);
+
+    let fail = move |error: &str| {
+        set_running.set(false);
+        let (year, day) = selected.get_untracked();
+        let parts: Vec<u8> = solvers(year, day).map(|solver| solver.part).collect();
+        set_results.update(|results| fail_missing(results, &parts, error));
+    };
+    // Never from the run's own callbacks, dropping the run drops them.
+    let stop = move |_| {
+        workers.set_value(None);
+        fail("stopped");
+    };
+    let pick_day = move |ev| {
+        if let Some(day) = parse_day(&event_target_value(&ev)) {
+            workers.set_value(None);
+            set_running.set(false);
+            set_selected.set(day);
+            set_results.set(Vec::new());
+        }
+    };
+    let run = move |_| {
+        let (year, day) = selected.get_untracked();
+        set_results.set(Vec::new());
+        set_running.set(true);
+        let started = input.with_untracked(|input| {
+            Run::start(
+                year,
+                day,
+                input,
+                move |result| {
+                    set_results.update(|results| {
+                        results.push(result);
+                        results.sort_by_key(|result| result.part);
+                    })
+                },
+                move || set_running.set(false),
+            )
+        });
+        match started {
+            Ok(run) => workers.set_value(Some(run)),
+            Err(error) => fail(&format!("couldn't start the solver: {error:?}")),
+        }
+    };
+
+    view! {
+        <Stylesheet id="leptos" href="/pkg/www.css" />
+        <Title text="Advent of Code playground" />
+        <main>
+            <h1>"Advent of Code playground"</h1>
+            <label>
+                "Day "
+                <select on:change=pick_day>
+                    {days
+                        .into_iter()
+                        .map(|(year, day)| {
+                            view! {
+                                <option value=format!("{year}-{day}")>
+                                    {format!("{year} day {day:02}")}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </label>
+            <textarea
+                placeholder="Paste your puzzle input"
+                prop:value=input
+                on:input=move |ev| set_input.set(event_target_value(&ev))
+            ></textarea>
+            <div class="actions">
+                <button on:click=run prop:disabled=running>
+                    "Solve"
+                </button>
+                <button on:click=stop prop:disabled=move || !running.get()>
+                    "Stop"
+                </button>
+            </div>
+            <table>
+                <thead>
+                    <tr>
+                        <th>"part"</th>
+                        <th>"answer"</th>
+                        <th>"time"</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {move || {
+                        results
+                            .get()
+                            .into_iter()
+                            .map(|result| view! { <ResultRow result /> })
+                            .collect_view()
+                    }}
+                </tbody>
+            </table>
+        </main>
+    }
+}
+
+#[component]
+fn ResultRow(result: PartResult) -> impl IntoView {
+    let (class, text) = match result.answer {
+        Ok(answer) => ("answer", answer),
+        Err(error) => ("error", error),
+    };
+    view! {
+        <tr>
+            <td>{result.part}</td>
+            <td class=class>{text}</td>
+            <td>{result.elapsed_ms.map(|elapsed| format!("{elapsed:.2} ms"))}</td>
+        </tr>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(Some((2023, 5)), parse_day("2023-5"));
+        assert_eq!(None, parse_day("2023"));
+        assert_eq!(None, parse_day("2023-x"));
+    }
+
+    #[test]
+    fn test_solve() {
+        let clock = Cell::new(0.0);
+        let now = || {
+            clock.set(clock.get() + 1.5);
+            clock.get()
+        };
+        let input = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+        let results: Vec<PartResult> = solvers(2023, 6)
+            .map(|solver| solve(solver, input, now))
+            .collect();
+        assert_eq!(
+            vec![
+                PartResult {
+                    part: 1,
+                    answer: Ok("288".to_string()),
+                    elapsed_ms: Some(1.5)
+                },
+                PartResult {
+                    part: 2,
+                    answer: Ok("71503".to_string()),
+                    elapsed_ms: Some(1.5)
+                },
+            ],
+            results
+        );
+        let solver = solvers(2023, 6).next().unwrap();
+        assert!(solve(solver, "Time: x", || 0.0).answer.is_err());
+    }
+
+    #[test]
+    fn test_solvers() {
+        assert_eq!(2, solvers(2023, 6).count());
+        assert_eq!(0, solvers(2023, 26).count());
+    }
+
+    #[test]
+    fn test_fail_missing() {
+        let mut results = vec![PartResult {
+            part: 2,
+            answer: Err("panicked".to_string()),
+            elapsed_ms: None,
+        }];
+        fail_missing(&mut results, &[1, 2], "stopped");
+        assert_eq!(
+            vec![
+                PartResult {
+                    part: 1,
+                    answer: Err("stopped".to_string()),
+                    elapsed_ms: None
+                },
+                PartResult {
+                    part: 2,
+                    answer: Err("panicked".to_string()),
+                    elapsed_ms: None
+                },
+            ],
+            results
+        );
+    }
+}
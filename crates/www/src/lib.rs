@@ -0,0 +1,18 @@
+pub mod app;
+pub mod worker;
+
+/// Entry point of the wasm bundle, takes over the page rendered by the server.
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn hydrate() {
+    console_error_panic_hook::set_once();
+    leptos::mount::hydrate_body(app::App);
+}
+
+/// Entry point of the same bundle loaded in the solver worker, see
+/// `public/solver.js`.
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn solver() {
+    worker::serve();
+}
@@ -0,0 +1,35 @@
+#[cfg(feature = "ssr")]
+#[tokio::main]
+async fn main() {
+    use axum::Router;
+    use leptos::logging::log;
+    use leptos::prelude::*;
+    use leptos_axum::{generate_route_list, LeptosRoutes};
+    use www::app::{shell, App};
+
+    let conf = get_configuration(None).expect("cargo-leptos provides the configuration");
+    let addr = conf.leptos_options.site_addr;
+    let leptos_options = conf.leptos_options;
+    let routes = generate_route_list(App);
+
+    let app = Router::new()
+        .leptos_routes(&leptos_options, routes, {
+            let leptos_options = leptos_options.clone();
+            move || shell(leptos_options.clone())
+        })
+        .fallback(leptos_axum::file_and_error_handler(shell))
+        .with_state(leptos_options);
+
+    log!("listening on http://{addr}");
+    let listener = tokio::net::TcpListener::bind(&addr)
+        .await
+        .expect("site address is free");
+    axum::serve(listener, app.into_make_service())
+        .await
+        .expect("server runs");
+}
+
+/// Everything happens in the browser, see `lib.rs`. `cargo-leptos` builds
+/// the server with the `ssr` feature.
+#[cfg(not(feature = "ssr"))]
+fn main() {}
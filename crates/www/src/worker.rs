@@ -0,0 +1,275 @@
+//! Solvers run in dedicated workers rather than on the page's thread: a long
+//! solve leaves the page responsive and can be stopped, and a panic, which
+//! aborts the wasm instance, only takes the worker of that part down with it.
+
+use std::cell::Cell;
+use std::panic;
+use std::rc::Rc;
+
+use aoc_registry::TODO_PANIC;
+use js_sys::Array;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{DedicatedWorkerGlobalScope, ErrorEvent, MessageEvent, Worker};
+
+use crate::app::{self, PartResult};
+
+/// Script the workers start from, see `public/solver.js`.
+const SCRIPT: &str = "/solver.js";
+
+/// What the page asks a worker to solve.
+struct Request {
+    year: u16,
+    day: u8,
+    part: u8,
+    input: String,
+}
+
+impl Request {
+    fn encode(&self) -> JsValue {
+        let fields = Array::of3(&self.year.into(), &self.day.into(), &self.part.into());
+        fields.push(&self.input.as_str().into());
+        fields.into()
+    }
+
+    fn decode(message: JsValue) -> Option<Self> {
+        let fields = message.dyn_into::<Array>().ok()?;
+        Some(Self {
+            year: fields.get(0).as_f64()? as u16,
+            day: fields.get(1).as_f64()? as u8,
+            part: fields.get(2).as_f64()? as u8,
+            input: fields.get(3).as_string()?,
+        })
+    }
+}
+
+/// What a worker tells the page.
+enum Reply {
+    /// The worker listens for the request, one sent earlier would be lost.
+    Ready,
+    Part(PartResult),
+}
+
+impl Reply {
+    fn encode(&self) -> JsValue {
+        match self {
+            Reply::Ready => "ready".into(),
+            Reply::Part(result) => {
+                let (ok, text) = match &result.answer {
+                    Ok(answer) => (true, answer),
+                    Err(error) => (false, error),
+                };
+                Array::of4(
+                    &result.part.into(),
+                    &ok.into(),
+                    &text.as_str().into(),
+                    &result.elapsed_ms.into(),
+                )
+                .into()
+            }
+        }
+    }
+
+    fn decode(message: JsValue) -> Option<Self> {
+        if message.as_string().as_deref() == Some("ready") {
+            return Some(Reply::Ready);
+        }
+        let fields = message.dyn_into::<Array>().ok()?;
+        let text = fields.get(2).as_string()?;
+        Some(Reply::Part(PartResult {
+            part: fields.get(0).as_f64()? as u8,
+            answer: if fields.get(1).as_bool()? {
+                Ok(text)
+            } else {
+                Err(text)
+            },
+            elapsed_ms: fields.get(3).as_f64(),
+        }))
+    }
+}
+
+/// A solve of every part of a day, each in its own worker. The workers are
+/// terminated once they answered or crashed, or when the run is dropped.
+pub struct Run {
+    _workers: Vec<PartWorker>,
+}
+
+impl Run {
+    /// Solves every part of `day` on `input`, calling `on_part` with each
+    /// answer as it comes, a crash being the answer of the part that crashed,
+    /// and `on_end` once every part has one.
+    pub fn start(
+        year: u16,
+        day: u8,
+        input: &str,
+        on_part: impl Fn(PartResult) + 'static,
+        on_end: impl Fn() + 'static,
+    ) -> Result<Self, JsValue> {
+        let on_part: Rc<dyn Fn(PartResult)> = Rc::new(on_part);
+        let pending = Rc::new(Cell::new(0));
+        let on_end: Rc<dyn Fn()> = Rc::new(on_end);
+
+        let workers = app::solvers(year, day)
+            .map(|solver| {
+                let request = Request {
+                    year,
+                    day,
+                    part: solver.part,
+                    input: input.to_string(),
+                };
+                pending.set(pending.get() + 1);
+                let pending = Rc::clone(&pending);
+                let on_end = Rc::clone(&on_end);
+                PartWorker::spawn(request, Rc::clone(&on_part), move || {
+                    pending.set(pending.get() - 1);
+                    if pending.get() == 0 {
+                        on_end();
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, JsValue>>()?;
+        if workers.is_empty() {
+            on_end();
+        }
+        Ok(Self { _workers: workers })
+    }
+}
+
+/// The worker of a single part.
+struct PartWorker {
+    worker: Worker,
+    _on_message: Closure<dyn Fn(MessageEvent)>,
+    _on_error: Closure<dyn Fn(ErrorEvent)>,
+}
+
+impl PartWorker {
+    /// Starts a worker on `request`, calling `on_part` with its answer and
+    /// then `on_done`, once.
+    fn spawn(
+        request: Request,
+        on_part: Rc<dyn Fn(PartResult)>,
+        on_done: impl Fn() + 'static,
+    ) -> Result<Self, JsValue> {
+        let worker = Worker::new(SCRIPT)?;
+        let part = request.part;
+        // Set once the part has its answer, the worker may crash after that.
+        let answered = Rc::new(Cell::new(false));
+        let answer = {
+            let worker = worker.clone();
+            let answered = Rc::clone(&answered);
+            move |result: PartResult| {
+                if !answered.replace(true) {
+                    worker.terminate();
+                    on_part(result);
+                    on_done();
+                }
+            }
+        };
+        let answer = Rc::new(answer);
+
+        let on_message = {
+            let worker = worker.clone();
+            let answer = Rc::clone(&answer);
+            let request = request.encode();
+            Closure::<dyn Fn(MessageEvent)>::new(move |event: MessageEvent| {
+                match Reply::decode(event.data()) {
+                    Some(Reply::Ready) => {
+                        if let Err(error) = worker.post_message(&request) {
+                            answer(crashed(part, &format!("{error:?}")));
+                        }
+                    }
+                    Some(Reply::Part(result)) => answer(result),
+                    None => {}
+                }
+            })
+        };
+        let on_error = Closure::<dyn Fn(ErrorEvent)>::new(move |event: ErrorEvent| {
+            event.prevent_default();
+            answer(crashed(part, &event.message()));
+        });
+        worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        worker.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+        Ok(Self {
+            worker,
+            _on_message: on_message,
+            _on_error: on_error,
+        })
+    }
+}
+
+impl Drop for PartWorker {
+    fn drop(&mut self) {
+        self.worker.terminate();
+    }
+}
+
+fn crashed(part: u8, error: &str) -> PartResult {
+    PartResult {
+        part,
+        answer: Err(format!("the solver crashed: {error}")),
+        elapsed_ms: None,
+    }
+}
+
+thread_local! {
+    /// The part the worker is solving, for the panic hook.
+    static PART: Cell<u8> = const { Cell::new(0) };
+}
+
+/// The worker's side: solves the requested part. A panic is reported as its
+/// answer before the instance aborts, `todo!()` as a part that isn't
+/// implemented yet.
+pub fn serve() {
+    panic::set_hook(Box::new(|info| {
+        let answer = match info.payload_as_str() {
+            Some(message) if message.starts_with(TODO_PANIC) => "not implemented yet".to_string(),
+            _ => info.to_string(),
+        };
+        post(Reply::Part(PartResult {
+            part: PART.get(),
+            answer: Err(answer),
+            elapsed_ms: None,
+        }));
+        console_error_panic_hook::hook(info);
+    }));
+
+    let scope = worker_scope();
+    let on_message = Closure::<dyn Fn(MessageEvent)>::new({
+        let scope = scope.clone();
+        move |event: MessageEvent| {
+            let Some(request) = Request::decode(event.data()) else {
+                return;
+            };
+            let now = || {
+                scope
+                    .performance()
+                    .map_or(0.0, |performance| performance.now())
+            };
+            PART.set(request.part);
+            let result = match aoc_registry::find(request.year, request.day, request.part) {
+                Ok(solver) => app::solve(solver, &request.input, now),
+                Err(error) => PartResult {
+                    part: request.part,
+                    answer: Err(error.to_string()),
+                    elapsed_ms: None,
+                },
+            };
+            post(Reply::Part(result));
+        }
+    });
+    scope.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    // The worker lives as long as the instance.
+    on_message.forget();
+
+    post(Reply::Ready);
+}
+
+fn worker_scope() -> DedicatedWorkerGlobalScope {
+    js_sys::global().unchecked_into()
+}
+
+fn post(reply: Reply) {
+    // The page is gone if this fails, there's nobody left to tell.
+    let _ = worker_scope().post_message(&reply.encode());
+}
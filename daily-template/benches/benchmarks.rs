@@ -0,0 +1,31 @@
+use aoc_solution::Solution;
+use y{{year}}_{{crate_name}}::{{project-name | upper_camel_case}};
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+fn read_input(part: u8) -> String {
+    aoc_input::Source::from_env()
+        .read(env!("CARGO_MANIFEST_DIR"), part)
+        .unwrap()
+}
+
+#[divan::bench]
+fn parse(bencher: divan::Bencher) {
+    let input = read_input(1);
+    bencher.bench(|| {{project-name | upper_camel_case}}::parse(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part1(bencher: divan::Bencher) {
+    let input = {{project-name | upper_camel_case}}::parse(&read_input(1)).unwrap();
+    bencher.bench(|| {{project-name | upper_camel_case}}::part1(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part2(bencher: divan::Bencher) {
+    let input = {{project-name | upper_camel_case}}::parse(&read_input(2)).unwrap();
+    bencher.bench(|| {{project-name | upper_camel_case}}::part2(divan::black_box(&input)).unwrap());
+}
@@ -0,0 +1,34 @@
+use aoc_solution::Solution;
+use miette::Context;
+#[cfg(feature = "tracy")]
+use tracing_subscriber::layer::SubscriberExt;
+use y{{year}}_{{crate_name}}::custom_error::AocError;
+use y{{year}}_{{crate_name}}::{{project-name | upper_camel_case}};
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    #[cfg(feature = "dhat-heap")]
+    let _profiler = dhat::Profiler::new_heap();
+
+    #[cfg(feature = "tracy")]
+    tracing::subscriber::set_global_default(
+        tracing_subscriber::registry().with(tracing_tracy::TracyLayer::new()),
+    )
+    .expect("no other subscriber is set");
+
+    #[cfg(not(any(feature = "dhat-heap", feature = "tracy")))]
+    tracing_subscriber::fmt::init();
+
+    let file = aoc_input::Source::from_args()
+        .read(env!("CARGO_MANIFEST_DIR"), 2)
+        .map_err(AocError::from)
+        .context("read input")?;
+    let input = {{project-name | upper_camel_case}}::parse(&file).context("parse input")?;
+    let result = {{project-name | upper_camel_case}}::part2(&input).context("process part 2")?;
+    println!("{}", result);
+    Ok(())
+}
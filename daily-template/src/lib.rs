@@ -0,0 +1,31 @@
+use aoc_solution::Solution;
+
+use crate::custom_error::AocError;
+use crate::parser::Input;
+
+pub mod custom_error;
+pub mod parser;
+
+pub mod part1;
+pub mod part2;
+
+pub struct {{project-name | upper_camel_case}};
+
+impl Solution for {{project-name | upper_camel_case}} {
+    type Input = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Input, AocError> {
+        parser::parse(input)
+    }
+
+    fn part1(input: &Input) -> Result<u64, AocError> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Input) -> Result<u64, AocError> {
+        part2::process(input)
+    }
+}
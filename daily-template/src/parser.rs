@@ -0,0 +1,11 @@
+use crate::custom_error::AocError;
+
+#[derive(Debug)]
+pub struct Input;
+
+#[tracing::instrument(skip_all)]
+pub fn parse(
+    _input: &str,
+) -> miette::Result<Input, AocError> {
+    todo!("{{year}} day {{day}} - parse");
+}
@@ -0,0 +1,29 @@
+use crate::custom_error::AocError;
+use crate::parser::Input;
+
+#[tracing::instrument(skip_all)]
+pub fn process(
+    _input: &Input,
+) -> miette::Result<u64, AocError> {
+    todo!("{{year}} day {{day}} - part 2");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use aoc_input::Fixture;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::parser::parse;
+
+    #[rstest]
+    fn test_process(
+        #[files("fixtures/*/part2.txt")] answer: PathBuf,
+    ) -> miette::Result<(), AocError> {
+        let fixture = Fixture::load(answer)?;
+        assert_eq!(fixture.expected, process(&parse(&fixture.input)?)?.to_string());
+        Ok(())
+    }
+}